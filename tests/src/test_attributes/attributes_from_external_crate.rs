@@ -1,7 +1,18 @@
+use types_reader_core as types_reader;
+use types_reader_macros::MacrosParameters;
+
+#[derive(MacrosParameters)]
+pub struct MyAttribute {
+    pub id: u32,
+    pub tags: Vec<u32>,
+}
+
 #[cfg(test)]
 mod tests {
     use types_reader_core::Attributes;
 
+    use super::MyAttribute;
+
     fn parse(src: &str) -> syn::DeriveInput {
         syn::parse_str(src).unwrap()
     }
@@ -90,4 +101,29 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    /// Attributes collects the repeated parameters, so the derived struct has to reject them itself
+    #[test]
+    fn test_repeated_param_is_rejected_by_derived_struct() {
+        let ast = parse(
+            r#"
+            #[my_attr(id: 1, id: 2, tags: [1])]
+            #[my_attr(id: 1, tags: [1], tags: [2])]
+            pub struct MyModel {
+                pub id: u32,
+            }
+        "#,
+        );
+
+        let attrs = Attributes::new(&ast, &ast.attrs).unwrap();
+        let my_attrs = attrs.get_attrs("my_attr").unwrap();
+
+        for (tokens_object, name) in my_attrs.iter().zip(["id", "tags"]) {
+            let err = TryInto::<MyAttribute>::try_into(tokens_object)
+                .err()
+                .unwrap();
+
+            assert_eq!(err.to_string(), format!("Duplicate parameter '{}'", name));
+        }
+    }
 }
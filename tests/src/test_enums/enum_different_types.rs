@@ -21,7 +21,7 @@ pub struct MyStruct {
 mod tests {
     use std::str::FromStr;
 
-    use types_reader_core::TokensObject;
+    use types_reader_core::{TokensObject, TypesReaderError};
 
     use super::MyStruct;
 
@@ -75,7 +75,7 @@ mod tests {
 
         let tokens: TokensObject = params.try_into().unwrap();
 
-        let my_struct: Result<MyStruct, syn::Error> = (&tokens).try_into();
+        let my_struct: Result<MyStruct, TypesReaderError> = (&tokens).try_into();
 
         assert_eq!(my_struct.is_err(), true);
     }
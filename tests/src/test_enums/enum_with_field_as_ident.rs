@@ -17,7 +17,7 @@ pub struct MyStruct {
 mod tests {
     use std::str::FromStr;
 
-    use types_reader_core::TokensObject;
+    use types_reader_core::{TokensObject, TypesReaderError};
 
     use super::MyStruct;
 
@@ -55,8 +55,34 @@ mod tests {
 
         let tokens: TokensObject = params.try_into().unwrap();
 
-        let my_struct: Result<MyStruct, syn::Error> = (&tokens).try_into();
+        let my_struct: Result<MyStruct, TypesReaderError> = (&tokens).try_into();
 
         assert_eq!(my_struct.is_err(), true);
     }
+
+    #[test]
+    fn test_misspelled_case_is_suggested() {
+        let params = r#"field1: Yess"#;
+
+        let params = proc_macro2::TokenStream::from_str(params).unwrap();
+
+        let tokens: TokensObject = params.try_into().unwrap();
+
+        let result: Result<MyStruct, _> = (&tokens).try_into();
+
+        let messages: Vec<String> = result
+            .err()
+            .unwrap()
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "in `field1`: Unsupported value 'Yess', did you mean 'Yes'?",
+                "help: supported values are: 'Yes', 'No'"
+            ]
+        );
+    }
 }
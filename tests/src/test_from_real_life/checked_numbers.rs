@@ -0,0 +1,68 @@
+use types_reader_core as types_reader;
+use types_reader_macros::MacrosParameters;
+
+#[derive(MacrosParameters)]
+pub struct CheckedNumbers {
+    pub id: u8,
+    pub offset: Option<u64>,
+    pub mask: Option<u128>,
+    pub ratio: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use types_reader_core::{TokensObject, TypesReaderError};
+
+    use super::CheckedNumbers;
+
+    fn parse(src: &str) -> Result<CheckedNumbers, TypesReaderError> {
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let tokens_object = TokensObject::new(token_stream.into()).unwrap();
+
+        (&tokens_object).try_into()
+    }
+
+    #[test]
+    fn test_numbers_in_range() {
+        let result = parse("id: 255, offset: 5").unwrap();
+
+        assert_eq!(result.id, 255);
+        assert_eq!(result.offset, Some(5));
+        assert!(result.mask.is_none());
+    }
+
+    #[test]
+    fn test_hex_128_bit_mask() {
+        let result = parse("id: 0b1, mask: 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF").unwrap();
+
+        assert_eq!(result.id, 1);
+        assert_eq!(result.mask, Some(u128::MAX));
+    }
+
+    #[test]
+    fn test_integer_as_float() {
+        let result = parse("id: 1, ratio: 2").unwrap();
+        assert_eq!(result.ratio, Some(2.0));
+
+        let result = parse("id: 1, ratio: 2.5e-1").unwrap();
+        assert_eq!(result.ratio, Some(0.25));
+    }
+
+    #[test]
+    fn test_numbers_out_of_range() {
+        let err = parse("id: 300").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "in `id`: value 300 does not fit into u8 (0..=255)"
+        );
+
+        let err = parse("id: 1, offset: -1").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "in `offset`: value -1 does not fit into u64 (0..=18446744073709551615)"
+        );
+    }
+}
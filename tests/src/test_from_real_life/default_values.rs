@@ -0,0 +1,107 @@
+use types_reader_core as types_reader;
+use types_reader_macros::{MacrosEnum, MacrosParameters};
+
+#[derive(MacrosEnum, Debug, PartialEq)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+fn default_host() -> String {
+    "localhost".to_string()
+}
+
+#[derive(MacrosParameters)]
+pub struct ServerAttribute<'s> {
+    #[positional(0)]
+    #[param(default = "/")]
+    pub path: &'s str,
+    #[param(default = 8080)]
+    pub port: u16,
+    #[param(default_fn = default_host)]
+    pub host: String,
+    #[param(default = "api")]
+    pub prefix: String,
+    #[param(default = 1)]
+    pub ratio: f64,
+    #[param(default = true)]
+    pub enabled: bool,
+    #[param(default = Method::Get)]
+    pub method: Method,
+    #[param(default)]
+    pub retries: u32,
+    #[param(default)]
+    pub tags: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use types_reader_core::TokensObject;
+
+    use super::{Method, ServerAttribute};
+
+    fn parse(src: &str) -> TokensObject {
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        TokensObject::new(token_stream.into()).unwrap()
+    }
+
+    #[test]
+    fn test_all_values_are_default() {
+        let tokens_object = parse("");
+        let result: ServerAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.path, "/");
+        assert_eq!(result.port, 8080);
+        assert_eq!(result.host, "localhost");
+        assert_eq!(result.prefix, "api");
+        assert_eq!(result.ratio, 1.0);
+        assert!(result.enabled);
+        assert_eq!(result.method, Method::Get);
+        assert_eq!(result.retries, 0);
+        assert!(result.tags.is_empty());
+    }
+
+    #[test]
+    fn test_values_override_defaults() {
+        let tokens_object = parse(
+            r#""/users", port: 80, host: "example.com", ratio: 0.5, enabled: false, method: "Post", retries: 3, tags: ["a", "b"]"#,
+        );
+        let result: ServerAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.path, "/users");
+        assert_eq!(result.port, 80);
+        assert_eq!(result.host, "example.com");
+        assert_eq!(result.prefix, "api");
+        assert_eq!(result.ratio, 0.5);
+        assert!(!result.enabled);
+        assert_eq!(result.method, Method::Post);
+        assert_eq!(result.retries, 3);
+        assert_eq!(result.tags, vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[test]
+    fn test_wrong_value_is_still_an_error() {
+        let tokens_object = parse(r#"port: "80""#);
+        let err = TryInto::<ServerAttribute>::try_into(&tokens_object)
+            .err()
+            .unwrap();
+
+        assert_eq!(err.to_string(), "in `port`: Value should be a number");
+    }
+
+    #[test]
+    fn test_unknown_param_is_still_an_error() {
+        let tokens_object = parse(r#"prot: 80"#);
+        let err = TryInto::<ServerAttribute>::try_into(&tokens_object)
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            err.to_string(),
+            "Unknown parameter 'prot', did you mean 'port'?"
+        );
+    }
+}
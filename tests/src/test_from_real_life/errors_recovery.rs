@@ -0,0 +1,56 @@
+use types_reader_core as types_reader;
+use types_reader_macros::MacrosParameters;
+
+#[derive(MacrosParameters)]
+pub struct ColumnAttribute<'s> {
+    pub name: &'s str,
+    pub size: u8,
+    pub nullable: bool,
+    pub description: Option<&'s str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use types_reader_core::TokensObject;
+
+    use super::ColumnAttribute;
+
+    #[test]
+    fn test_every_field_error_is_reported() {
+        let src = r#"name: 5, size: 300, nullable: true, descripton: "text""#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let tokens_object = TokensObject::new(token_stream.into()).unwrap();
+
+        let result: Result<ColumnAttribute, _> = (&tokens_object).try_into();
+
+        let messages: Vec<String> = result
+            .err()
+            .unwrap()
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect();
+
+        assert_eq!(messages.len(), 4);
+        assert_eq!(
+            messages[0],
+            "Unknown parameter 'descripton', did you mean 'description'?"
+        );
+        assert!(messages[1].starts_with("help: supported parameters are:"));
+        assert!(messages[3].contains("does not fit into u8"));
+    }
+
+    #[test]
+    fn test_parsing_continues_after_broken_param() {
+        let src = r#"name "id", size: , nullable: false"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let err = TokensObject::new(token_stream.into()).unwrap_err();
+
+        assert_eq!(err.into_iter().count(), 2);
+    }
+}
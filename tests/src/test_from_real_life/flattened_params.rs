@@ -0,0 +1,85 @@
+use types_reader_core as types_reader;
+use types_reader_macros::MacrosParameters;
+
+#[derive(MacrosParameters)]
+#[params(exclusive(description, summary))]
+pub struct CommonParams<'s> {
+    pub description: Option<&'s str>,
+    pub summary: Option<&'s str>,
+    #[has_attribute]
+    pub deprecated: bool,
+    pub tags: Option<Vec<&'s str>>,
+}
+
+#[derive(MacrosParameters)]
+pub struct GetAttribute<'s> {
+    pub route: &'s str,
+    #[param(flatten)]
+    pub common: CommonParams<'s>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use types_reader_core::TokensObject;
+
+    use super::GetAttribute;
+
+    fn parse(src: &str) -> TokensObject {
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        TokensObject::new(token_stream.into()).unwrap()
+    }
+
+    fn read_errors(src: &str) -> Vec<String> {
+        let tokens_object = parse(src);
+
+        TryInto::<GetAttribute>::try_into(&tokens_object)
+            .err()
+            .unwrap()
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_flattened_fields_are_read_from_the_same_level() {
+        let tokens_object =
+            parse(r#"route: "/users", description: "Users", deprecated, tags: ["users"]"#);
+        let result: GetAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.route, "/users");
+        assert_eq!(result.common.description, Some("Users"));
+        assert!(result.common.deprecated);
+        assert_eq!(result.common.tags, Some(vec!["users"]));
+
+        assert_eq!(
+            GetAttribute::get_param_names(),
+            vec!["route", "description", "summary", "deprecated", "tags"]
+        );
+    }
+
+    #[test]
+    fn test_unknown_params_are_checked_with_flattened_names() {
+        assert_eq!(
+            read_errors(r#"route: "/users", tagz: ["users"]"#),
+            vec![
+                "Unknown parameter 'tagz', did you mean 'tags'?",
+                "help: supported parameters are: 'route', 'description', 'summary', 'deprecated', 'tags'"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_errors_of_flattened_fields() {
+        assert_eq!(
+            read_errors(r#"route: "/users", description: "Users", summary: 5"#),
+            vec![
+                "Only one of the parameters 'description', 'summary' can be given",
+                "Only one of the parameters 'description', 'summary' can be given",
+                "in `summary`: Type should be a string"
+            ]
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use types_reader_core as types_reader;
+use types_reader_macros::MacrosParameters;
+
+#[derive(MacrosParameters)]
+pub struct Ranged<T: Copy + PartialOrd> {
+    pub min: T,
+    pub max: T,
+    pub step: Option<T>,
+}
+
+#[derive(MacrosParameters)]
+pub struct SliderAttribute<'s, T>
+where
+    T: Copy + PartialOrd,
+{
+    pub label: &'s str,
+    pub marks: Vec<&'s str>,
+    pub hint: Option<&'s str>,
+    #[param(flatten)]
+    pub range: Ranged<T>,
+}
+
+#[derive(MacrosParameters)]
+pub struct PortAttribute<const MAX: u32> {
+    #[param(range = 1..=MAX)]
+    pub port: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use types_reader_core::TokensObject;
+
+    use super::{PortAttribute, Ranged, SliderAttribute};
+
+    fn parse(src: &str) -> TokensObject {
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        TokensObject::new(token_stream.into()).unwrap()
+    }
+
+    #[test]
+    fn test_type_param() {
+        let tokens_object = parse("min: 1, max: 10");
+        let result: Ranged<i32> = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.min, 1);
+        assert_eq!(result.max, 10);
+        assert_eq!(result.step, None);
+
+        let tokens_object = parse("min: 0.5, max: 1.5, step: 0.25");
+        let result: Ranged<f64> = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.min, 0.5);
+        assert_eq!(result.max, 1.5);
+        assert_eq!(result.step, Some(0.25));
+    }
+
+    #[test]
+    fn test_type_param_mismatch() {
+        let tokens_object = parse("min: 1, max: 300");
+        let result: Result<Ranged<u8>, _> = (&tokens_object).try_into();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_type_param_with_lifetime_and_flatten() {
+        let tokens_object = parse(r#"label: "Volume", marks: ["low", "high"], min: 0, max: 100"#);
+        let result: SliderAttribute<u8> = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.label, "Volume");
+        assert_eq!(result.marks, vec!["low", "high"]);
+        assert_eq!(result.hint, None);
+        assert_eq!(result.range.min, 0);
+        assert_eq!(result.range.max, 100);
+
+        assert_eq!(
+            SliderAttribute::<u8>::get_param_names(),
+            vec!["label", "marks", "hint", "min", "max", "step"]
+        );
+    }
+
+    #[test]
+    fn test_const_param() {
+        let tokens_object = parse("port: 8080");
+        let result: PortAttribute<65535> = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.port, 8080);
+
+        let result: Result<PortAttribute<1024>, _> = (&tokens_object).try_into();
+
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "in `port`: Value should be in the range 1..=1024"
+        );
+    }
+}
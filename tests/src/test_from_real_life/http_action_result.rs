@@ -17,3 +17,99 @@ pub struct HttpActionResult<'s> {
     pub as_vec: Vec<SubParameters<'s>>,
     pub as_vec_opt: Option<Vec<SubParameters<'s>>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use types_reader_core::{ErrorKind, TokensObject, TypesReaderError};
+
+    use super::HttpActionResult;
+
+    fn get_error_messages(src: &str) -> Vec<String> {
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let tokens_object = TokensObject::new(token_stream.into()).unwrap();
+
+        let result: Result<HttpActionResult, TypesReaderError> = (&tokens_object).try_into();
+
+        result
+            .err()
+            .unwrap()
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_error_in_vec_item_has_path() {
+        let messages = get_error_messages(
+            r#"status_code: 200,
+            description: {sub_parameter1: "a", sub_parameter2: "b"},
+            as_vec: [
+                {sub_parameter1: "a", sub_parameter2: "b"},
+                {sub_parameter1: "a", sub_parameter2: "b"},
+                {sub_parameter1: 5, sub_parameter2: "b"},
+            ]"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec!["in `as_vec[2].sub_parameter1`: Type should be a string"]
+        );
+    }
+
+    #[test]
+    fn test_error_in_nested_object_has_path() {
+        let messages = get_error_messages(
+            r#"status_code: 200,
+            description: {sub_parameter1: "a"},
+            as_vec: [],
+            as_vec_opt: [{sub_parameter1: "a", sub_parameter2: b}]"#,
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                "in `description`: Field 'sub_parameter2' is required...",
+                "in `as_vec_opt[0].sub_parameter2`: Type should be a string"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_field_is_reported_without_path() {
+        let messages = get_error_messages(
+            r#"description: {sub_parameter1: "a", sub_parameter2: "b"}, as_vec: []"#,
+        );
+
+        assert_eq!(messages, vec!["Field 'status_code' is required..."]);
+    }
+
+    #[test]
+    fn test_error_in_nested_object_keeps_kind() {
+        let token_stream = proc_macro2::TokenStream::from_str(
+            r#"status_code: 200,
+            description: {sub_parameter1: 5, sub_parameter2: "b"},
+            as_vec: []"#,
+        )
+        .unwrap();
+
+        let tokens_object = TokensObject::new(token_stream.into()).unwrap();
+
+        let result: Result<HttpActionResult, TypesReaderError> = (&tokens_object).try_into();
+        let err = result.err().unwrap();
+
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::TypeMismatch {
+                expected: "string".to_string(),
+                found: "number".to_string()
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "in `description.sub_parameter1`: Type should be a string"
+        );
+    }
+}
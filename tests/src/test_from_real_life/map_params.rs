@@ -0,0 +1,106 @@
+use std::collections::{BTreeMap, HashMap};
+
+use types_reader_core as types_reader;
+use types_reader_core::OrderedMap;
+use types_reader_macros::MacrosParameters;
+
+#[derive(MacrosParameters)]
+pub struct HttpRouteAttribute<'s> {
+    pub headers: HashMap<String, &'s str>,
+    #[param(non_empty)]
+    pub labels: BTreeMap<&'s str, u32>,
+    pub flags: Option<OrderedMap<&'s str, bool>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use types_reader_core::TokensObject;
+
+    use super::HttpRouteAttribute;
+
+    fn parse(src: &str) -> TokensObject {
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        TokensObject::new(token_stream.into()).unwrap()
+    }
+
+    fn read_errors(src: &str) -> Vec<String> {
+        let tokens_object = parse(src);
+
+        TryInto::<HttpRouteAttribute>::try_into(&tokens_object)
+            .err()
+            .unwrap()
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_maps_are_read_from_objects() {
+        let tokens_object = parse(
+            r#"headers: { "x-id": "1", accept: "json" }, labels(zone: 2, app: 1), flags: { z: true, a: false }"#,
+        );
+        let result: HttpRouteAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.headers.len(), 2);
+        assert_eq!(result.headers["x-id"], "1");
+        assert_eq!(result.headers["accept"], "json");
+
+        let labels: Vec<(&str, u32)> = result.labels.into_iter().collect();
+        assert_eq!(labels, vec![("app", 1), ("zone", 2)]);
+
+        let flags: Vec<(&str, bool)> = result
+            .flags
+            .unwrap()
+            .iter()
+            .map(|(key, value)| (*key, *value))
+            .collect();
+        assert_eq!(flags, vec![("z", true), ("a", false)]);
+    }
+
+    #[test]
+    fn test_empty_map() {
+        let tokens_object = parse(r#"headers: {}, labels(app: 1)"#);
+        let result: HttpRouteAttribute = (&tokens_object).try_into().unwrap();
+
+        assert!(result.headers.is_empty());
+        assert!(result.flags.is_none());
+    }
+
+    #[test]
+    fn test_map_errors() {
+        let errors =
+            read_errors(r#"headers: { accept: 1, "x-id": "1" }, labels: { app: "one", zone: 2 }"#);
+
+        assert_eq!(
+            errors,
+            vec![
+                "in `headers.accept`: Type should be a string".to_string(),
+                "in `labels.app`: Value should be a number".to_string(),
+            ]
+        );
+
+        let errors = read_errors(r#"headers: "json", labels: {}"#);
+
+        assert_eq!(
+            errors,
+            vec![
+                "in `headers`: Value should be an object. E.g.: { accept: \"json\" }".to_string(),
+                "in `labels`: Value should not be empty".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_key() {
+        let token_stream =
+            proc_macro2::TokenStream::from_str(r#"headers: { accept: "a", "accept": "b" }"#)
+                .unwrap();
+
+        let err = TokensObject::new(token_stream.into()).unwrap_err();
+
+        assert_eq!(err.to_string(), "Duplicate parameter 'accept'");
+    }
+}
@@ -1,5 +1,23 @@
+mod checked_numbers;
 mod default_as_object_value;
 mod default_as_optional_object_value;
 mod default_as_tokens_object;
+mod default_values;
+mod errors_recovery;
+mod flattened_params;
+mod generic_params;
 mod http_action_result;
+mod map_params;
+mod param_aliases;
+mod param_constraints;
+mod parse_with_fields;
+mod path_values;
+mod positional_params;
+mod raw_token_values;
+mod renamed_params;
+mod rest_params;
+mod sequence_params;
 mod single_or_named_and_ident;
+mod struct_lifetimes;
+mod text_literals;
+mod validated_params;
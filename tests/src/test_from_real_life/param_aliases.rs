@@ -0,0 +1,85 @@
+use types_reader_core as types_reader;
+use types_reader_macros::MacrosParameters;
+
+#[derive(MacrosParameters)]
+pub struct DocAttribute<'s> {
+    #[param(alias = "desc", deprecated = "use `description`")]
+    pub description: &'s str,
+    #[param(alias = "ty", alias = "kind")]
+    pub r#type: Option<&'s str>,
+    #[param(deprecated = "is not used anymore")]
+    pub legacy: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use types_reader_core::TokensObject;
+
+    use super::DocAttribute;
+
+    fn parse(src: &str) -> TokensObject {
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        TokensObject::new(token_stream.into()).unwrap()
+    }
+
+    #[test]
+    fn test_param_is_read_by_name_or_alias() {
+        let tokens_object = parse(r#"description: "text", type: "int""#);
+        let result: DocAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.description, "text");
+        assert_eq!(result.r#type, Some("int"));
+
+        let tokens_object = parse(r#"desc: "text", kind: "int""#);
+        let result: DocAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.description, "text");
+        assert_eq!(result.r#type, Some("int"));
+
+        let tokens_object = parse(r#"description: "text""#);
+        let result: DocAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.r#type, None);
+    }
+
+    #[test]
+    fn test_param_given_by_name_and_alias() {
+        let tokens_object = parse(r#"description: "text", ty: "int", type: "float""#);
+
+        let messages: Vec<String> = TryInto::<DocAttribute>::try_into(&tokens_object)
+            .err()
+            .unwrap()
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "in `type`: Parameter 'type' is given as 'type' and as 'ty'",
+                "in `type`: Parameter 'type' is first given here"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deprecated_params_are_rendered_as_warnings() {
+        let tokens_object = parse(r#"desc: "text", legacy: true"#);
+
+        let warnings = DocAttribute::render_deprecation_warnings(&tokens_object).to_string();
+
+        assert!(warnings.contains("deprecated (note = \"use `description`\")"));
+        assert!(warnings.contains("const desc"));
+        assert!(warnings.contains("deprecated (note = \"is not used anymore\")"));
+        assert!(warnings.contains("const legacy"));
+
+        let tokens_object = parse(r#"description: "text""#);
+
+        let warnings = DocAttribute::render_deprecation_warnings(&tokens_object);
+
+        assert!(warnings.is_empty());
+    }
+}
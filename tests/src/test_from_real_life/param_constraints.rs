@@ -0,0 +1,118 @@
+use types_reader_core as types_reader;
+use types_reader_macros::MacrosParameters;
+
+#[derive(MacrosParameters)]
+#[params(
+    exclusive(path, regex),
+    one_of_required(path, regex),
+    together(username, password)
+)]
+pub struct RouteAttribute<'s> {
+    pub path: Option<&'s str>,
+    #[param(alias = "re")]
+    pub regex: Option<&'s str>,
+    pub username: Option<&'s str>,
+    pub password: Option<&'s str>,
+    pub cache: Option<bool>,
+    #[param(requires = "cache")]
+    pub ttl: Option<u32>,
+}
+
+#[derive(MacrosParameters)]
+#[rustfmt::skip]
+#[params(together(username, password))]
+pub struct CredentialsAttribute<'s> {
+    pub username: Option<&'s str>,
+    pub password: Option<&'s str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use types_reader_core::TokensObject;
+
+    use super::{CredentialsAttribute, RouteAttribute};
+
+    fn parse(src: &str) -> TokensObject {
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        TokensObject::new(token_stream.into()).unwrap()
+    }
+
+    fn read_errors(src: &str) -> Vec<String> {
+        let tokens_object = parse(src);
+
+        TryInto::<RouteAttribute>::try_into(&tokens_object)
+            .err()
+            .unwrap()
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_constraints_are_satisfied() {
+        let tokens_object = parse(r#"path: "/users""#);
+        let result: RouteAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.path, Some("/users"));
+
+        let tokens_object =
+            parse(r#"re: "^/users$", username: "admin", password: "secret", cache: true, ttl: 60"#);
+        let result: RouteAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.regex, Some("^/users$"));
+        assert_eq!(result.ttl, Some(60));
+    }
+
+    #[test]
+    fn test_exclusive_params() {
+        assert_eq!(
+            read_errors(r#"path: "/users", re: "^/users$""#),
+            vec![
+                "Only one of the parameters 'path', 're' can be given",
+                "Only one of the parameters 'path', 're' can be given"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_required_and_together_params() {
+        assert_eq!(
+            read_errors(r#"username: "admin""#),
+            vec![
+                "Parameters 'username', 'password' should be given together. Missing: 'password'",
+                "One of the parameters 'path', 'regex' is required"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_param_requires_other_param() {
+        let expected = vec!["Parameter 'ttl' requires 'cache' to be given"];
+
+        assert_eq!(read_errors(r#"path: "/users", ttl: 60"#), expected);
+        assert_eq!(
+            read_errors(r#"path: "/users", ttl: 60, cache: false"#),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_params_next_to_foreign_struct_attribute() {
+        let tokens_object = parse(r#"username: "admin", password: "secret""#);
+        let result: CredentialsAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.username, Some("admin"));
+
+        let err = TryInto::<CredentialsAttribute>::try_into(&parse(r#"username: "admin""#))
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            err.to_string(),
+            "Parameters 'username', 'password' should be given together. Missing: 'password'"
+        );
+    }
+}
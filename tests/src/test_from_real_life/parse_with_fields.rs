@@ -0,0 +1,97 @@
+use std::{net::IpAddr, time::Duration};
+
+use types_reader_core as types_reader;
+use types_reader_core::{OptionalObjectValue, TokensObject};
+use types_reader_macros::MacrosParameters;
+
+fn parse_ip(value: &TokensObject) -> Result<IpAddr, syn::Error> {
+    let src: &str = value.try_into()?;
+
+    src.parse()
+        .map_err(|_| value.throw_error_at_value_token("Invalid IP address"))
+}
+
+// 500ms, 10s
+fn parse_duration(value: &OptionalObjectValue) -> Result<Duration, syn::Error> {
+    let src: &str = value.try_into()?;
+
+    let result = match src.strip_suffix("ms") {
+        Some(millis) => millis.parse().map(Duration::from_millis),
+        None => src.trim_end_matches('s').parse().map(Duration::from_secs),
+    };
+
+    result.map_err(|_| value.throw_error("Invalid duration"))
+}
+
+fn default_interval() -> Duration {
+    Duration::from_secs(1)
+}
+
+#[derive(MacrosParameters)]
+pub struct UpstreamAttribute {
+    #[positional(0)]
+    #[param(parse_with = parse_ip)]
+    pub host: IpAddr,
+    #[param(parse_with = parse_duration)]
+    pub timeout: Option<Duration>,
+    #[param(parse_with = parse_duration, default_fn = default_interval)]
+    pub interval: Duration,
+    #[param(parse_with = parse_ip)]
+    pub mirrors: Vec<IpAddr>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, time::Duration};
+
+    use types_reader_core::TokensObject;
+
+    use super::UpstreamAttribute;
+
+    fn parse(src: &str) -> TokensObject {
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        TokensObject::new(token_stream.into()).unwrap()
+    }
+
+    #[test]
+    fn test_fields_are_read_with_functions() {
+        let tokens_object =
+            parse(r#""10.0.0.1", timeout: "500ms", interval: "5s", mirrors: ["10.0.0.2", "::1"]"#);
+        let result: UpstreamAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.host.to_string(), "10.0.0.1");
+        assert_eq!(result.timeout, Some(Duration::from_millis(500)));
+        assert_eq!(result.interval, Duration::from_secs(5));
+        assert_eq!(result.mirrors.len(), 2);
+        assert!(result.mirrors[1].is_ipv6());
+
+        let tokens_object = parse(r#""10.0.0.1", mirrors: []"#);
+        let result: UpstreamAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.timeout, None);
+        assert_eq!(result.interval, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_errors_of_the_functions() {
+        let tokens_object =
+            parse(r#""localhost", timeout: "soon", mirrors: ["10.0.0.2", "10.0.0"]"#);
+
+        let messages: Vec<String> = TryInto::<UpstreamAttribute>::try_into(&tokens_object)
+            .err()
+            .unwrap()
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "in `#0`: Invalid IP address",
+                "in `timeout`: Invalid duration",
+                "in `mirrors[1]`: Invalid IP address",
+            ]
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use types_reader_core as types_reader;
+use types_reader_macros::MacrosParameters;
+
+#[derive(MacrosParameters)]
+pub struct RouteAttribute<'s> {
+    pub handler: syn::Path,
+    pub model: &'s syn::Path,
+    pub name: syn::Ident,
+    pub fallback: Option<syn::Path>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use types_reader_core::TokensObject;
+
+    use super::RouteAttribute;
+
+    #[test]
+    fn test_path_fields() {
+        let src = r#"handler: crate::api::get_user, model: models::User, name: get_user"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let tokens_object = TokensObject::new(token_stream.into()).unwrap();
+
+        let result: RouteAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.handler.segments.len(), 3);
+        assert_eq!(
+            result.model.segments.last().unwrap().ident.to_string(),
+            "User"
+        );
+        assert_eq!(result.name.to_string(), "get_user");
+        assert!(result.fallback.is_none());
+    }
+}
@@ -0,0 +1,86 @@
+use types_reader_core as types_reader;
+use types_reader_macros::MacrosParameters;
+
+#[derive(MacrosParameters)]
+pub struct RouteAttribute<'s> {
+    #[positional(0)]
+    pub path: &'s str,
+    #[positional(1)]
+    pub limit: Option<u32>,
+    #[allow_ident]
+    pub method: &'s str,
+    pub auth: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use types_reader_core::TokensObject;
+
+    use super::RouteAttribute;
+
+    #[test]
+    fn test_positional_and_named_fields() {
+        let src = r#""/users/{id}", 10, method: GET, auth: true"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let tokens_object = TokensObject::new(token_stream.into()).unwrap();
+
+        let result: RouteAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.path, "/users/{id}");
+        assert_eq!(result.limit, Some(10));
+        assert_eq!(result.method, "GET");
+        assert_eq!(result.auth, Some(true));
+    }
+
+    #[test]
+    fn test_optional_positional_field_is_missing() {
+        let src = r#""/users", method: POST"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let tokens_object = TokensObject::new(token_stream.into()).unwrap();
+
+        let result: RouteAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.path, "/users");
+        assert!(result.limit.is_none());
+    }
+
+    #[test]
+    fn test_too_many_positional_params() {
+        let src = r#""/users", 10, 20, method: POST"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let tokens_object = TokensObject::new(token_stream.into()).unwrap();
+
+        let result: Result<RouteAttribute, _> = (&tokens_object).try_into();
+
+        assert!(result
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("Unexpected positional parameter"));
+    }
+
+    #[test]
+    fn test_positional_field_can_not_be_passed_by_name() {
+        let src = r#"path: "/users", method: POST"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let tokens_object = TokensObject::new(token_stream.into()).unwrap();
+
+        let result: Result<RouteAttribute, _> = (&tokens_object).try_into();
+
+        assert!(result
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("Unknown parameter 'path'"));
+    }
+}
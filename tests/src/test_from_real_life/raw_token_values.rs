@@ -0,0 +1,55 @@
+use types_reader_core as types_reader;
+use types_reader_macros::MacrosParameters;
+
+#[derive(MacrosParameters)]
+pub struct ModelAttribute {
+    pub ty: syn::Type,
+    pub default: syn::Expr,
+    pub validate: Option<syn::ExprClosure>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use quote::ToTokens;
+    use types_reader_core::TokensObject;
+
+    use super::ModelAttribute;
+
+    #[test]
+    fn test_raw_token_fields() {
+        let src =
+            r#"ty: HashMap<String, Vec<u8>>, default: HashMap::new(), validate: |v| !v.is_empty()"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let tokens_object = TokensObject::new(token_stream.into()).unwrap();
+
+        let result: ModelAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(
+            result.ty.to_token_stream().to_string(),
+            "HashMap < String , Vec < u8 > >"
+        );
+        assert_eq!(
+            result.default.to_token_stream().to_string(),
+            "HashMap :: new ()"
+        );
+        assert_eq!(result.validate.unwrap().inputs.len(), 1);
+    }
+
+    #[test]
+    fn test_optional_closure_is_missing() {
+        let src = r#"ty: u8, default: 0"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let tokens_object = TokensObject::new(token_stream.into()).unwrap();
+
+        let result: ModelAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.ty.to_token_stream().to_string(), "u8");
+        assert!(result.validate.is_none());
+    }
+}
@@ -0,0 +1,95 @@
+use types_reader_core as types_reader;
+use types_reader_macros::MacrosParameters;
+
+#[derive(MacrosParameters)]
+#[params(rename_all = "kebab-case")]
+pub struct HeaderAttribute<'s> {
+    pub content_type: &'s str,
+    pub max_body_size: Option<u32>,
+    #[param(rename = "type")]
+    pub kind: Option<&'s str>,
+    pub r#async: Option<bool>,
+}
+
+#[derive(MacrosParameters)]
+#[params(rename_all = "camelCase")]
+pub struct ColumnAttribute<'s> {
+    pub column_name: &'s str,
+    #[param(rename = "null")]
+    pub is_nullable: Option<bool>,
+    pub r#type: Option<&'s str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use types_reader_core::TokensObject;
+
+    use super::{ColumnAttribute, HeaderAttribute};
+
+    fn parse(src: &str) -> TokensObject {
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        TokensObject::new(token_stream.into()).unwrap()
+    }
+
+    #[test]
+    fn test_kebab_case_params() {
+        let tokens_object =
+            parse(r#"content-type: "json", max-body-size: 1024, type: "header", async: true"#);
+
+        let result: HeaderAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.content_type, "json");
+        assert_eq!(result.max_body_size, Some(1024));
+        assert_eq!(result.kind, Some("header"));
+        assert_eq!(result.r#async, Some(true));
+    }
+
+    #[test]
+    fn test_camel_case_params() {
+        let tokens_object = parse(r#"columnName: "id", null: true, type: "int""#);
+
+        let result: ColumnAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.column_name, "id");
+        assert_eq!(result.is_nullable, Some(true));
+        assert_eq!(result.r#type, Some("int"));
+    }
+
+    #[test]
+    fn test_field_name_is_unknown_param() {
+        let tokens_object = parse(r#"content_type: "json""#);
+
+        let messages: Vec<String> = TryInto::<HeaderAttribute>::try_into(&tokens_object)
+            .err()
+            .unwrap()
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "Unknown parameter 'content_type', did you mean 'content-type'?",
+                "help: supported parameters are: 'content-type', 'max-body-size', 'type', 'async'",
+                "Field 'content-type' is required..."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_error_path_uses_param_name() {
+        let tokens_object = parse(r#"content-type: "json", max-body-size: "big""#);
+
+        let err = TryInto::<HeaderAttribute>::try_into(&tokens_object)
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            err.to_string(),
+            "in `max-body-size`: Value should be a number"
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use types_reader_core as types_reader;
+use types_reader_core::{OrderedMap, TokensObject};
+use types_reader_macros::MacrosParameters;
+
+#[derive(MacrosParameters)]
+pub struct FieldAttribute<'s> {
+    pub rename: Option<&'s str>,
+    #[param(rest)]
+    pub serde: Vec<(&'s syn::Ident, &'s TokensObject)>,
+}
+
+#[derive(MacrosParameters)]
+pub struct HeadersAttribute<'s> {
+    pub name: &'s str,
+    #[param(rest)]
+    pub headers: OrderedMap<String, &'s TokensObject>,
+}
+
+#[derive(MacrosParameters)]
+#[params(allow_unknown)]
+pub struct LenientAttribute<'s> {
+    pub name: &'s str,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use types_reader_core::TokensObject;
+
+    use super::{FieldAttribute, HeadersAttribute, LenientAttribute};
+
+    fn parse(src: &str) -> TokensObject {
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        TokensObject::new(token_stream.into()).unwrap()
+    }
+
+    #[test]
+    fn test_rest_collects_unknown_params_in_source_order() {
+        let tokens_object =
+            parse(r#"skip_serializing_if: "Option::is_none", rename: "id", default"#);
+        let result: FieldAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.rename, Some("id"));
+
+        let keys: Vec<String> = result
+            .serde
+            .iter()
+            .map(|(key, _)| key.to_string())
+            .collect();
+        assert_eq!(keys, vec!["skip_serializing_if", "default"]);
+
+        let value: &str = result.serde[0].1.try_into().unwrap();
+        assert_eq!(value, "Option::is_none");
+        assert!(result.serde[1].1.has_no_value());
+    }
+
+    #[test]
+    fn test_rest_as_ordered_map() {
+        let tokens_object = parse(r#"content-type: "json", name: "api", x-request-id: 5"#);
+        let result: HeadersAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.name, "api");
+
+        let names: Vec<&String> = result.headers.keys().collect();
+        assert_eq!(names, vec!["content-type", "x-request-id"]);
+
+        let content_type = *result.headers.get("content-type").unwrap();
+        let value: &str = content_type.try_into().unwrap();
+        assert_eq!(value, "json");
+    }
+
+    #[test]
+    fn test_allow_unknown() {
+        let tokens_object = parse(r#"name: "api", anything: 5"#);
+        let result: LenientAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.name, "api");
+    }
+}
@@ -0,0 +1,97 @@
+use std::collections::{BTreeSet, HashSet};
+
+use types_reader_core as types_reader;
+use types_reader_macros::MacrosParameters;
+
+#[derive(MacrosParameters)]
+pub struct ChartAttribute<'s> {
+    pub point: (f64, f64),
+    pub range: Option<(u32, &'s str)>,
+    pub color: [u8; 3],
+    pub methods: HashSet<&'s str>,
+    #[param(non_empty)]
+    pub tags: Option<BTreeSet<String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use types_reader_core::TokensObject;
+
+    use super::ChartAttribute;
+
+    fn parse(src: &str) -> TokensObject {
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        TokensObject::new(token_stream.into()).unwrap()
+    }
+
+    fn read_errors(src: &str) -> Vec<String> {
+        let tokens_object = parse(src);
+
+        TryInto::<ChartAttribute>::try_into(&tokens_object)
+            .err()
+            .unwrap()
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_sequences_are_read_from_arrays() {
+        let tokens_object = parse(
+            r#"point: [1.5, 2], range: [1, "sec"], color: [255, 0, 128], methods: ["GET", "POST"], tags: ["b", "a"]"#,
+        );
+        let result: ChartAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.point, (1.5, 2.0));
+        assert_eq!(result.range, Some((1, "sec")));
+        assert_eq!(result.color, [255, 0, 128]);
+        assert_eq!(result.methods.len(), 2);
+        assert!(result.methods.contains("GET"));
+
+        let tags: Vec<String> = result.tags.unwrap().into_iter().collect();
+        assert_eq!(tags, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_sequences_are_read_from_tuples() {
+        let tokens_object = parse(r#"point: (0, -1), color: (1, 2, 3), methods: ()"#);
+        let result: ChartAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.point, (0.0, -1.0));
+        assert_eq!(result.range, None);
+        assert_eq!(result.color, [1, 2, 3]);
+        assert!(result.methods.is_empty());
+        assert!(result.tags.is_none());
+    }
+
+    #[test]
+    fn test_sequence_errors() {
+        let errors = read_errors(
+            r#"point: [1], range: ["1", "sec"], color: [1, 2, 3, 4], methods: ["GET", "POST", "GET"], tags: []"#,
+        );
+
+        assert_eq!(
+            errors,
+            vec![
+                "in `point`: Value should have 2 items. Found 1".to_string(),
+                "in `range[0]`: Value should be a number".to_string(),
+                "in `color`: Value should have 3 items. Found 4".to_string(),
+                "in `methods[2]`: Item is already in the set".to_string(),
+                "in `tags`: Value should not be empty".to_string(),
+            ]
+        );
+
+        let errors = read_errors(r#"point: 1, color: [1, 2, 300], methods: []"#);
+
+        assert_eq!(
+            errors,
+            vec![
+                "in `point`: Value should be an array or a tuple. E.g.: [1, 2]".to_string(),
+                "in `color[2]`: value 300 does not fit into u8 (0..=255)".to_string(),
+            ]
+        );
+    }
+}
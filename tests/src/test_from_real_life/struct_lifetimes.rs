@@ -0,0 +1,61 @@
+use types_reader_core as types_reader;
+use types_reader_core::TokensObject;
+use types_reader_macros::MacrosParameters;
+
+#[derive(MacrosParameters)]
+pub struct ColumnAttribute<'a> {
+    pub name: &'a str,
+    pub comment: Option<&'a str>,
+}
+
+#[derive(MacrosParameters)]
+pub struct TableAttribute<'a, 'b> {
+    pub name: &'a str,
+    pub schema: &'b str,
+    pub primary_key: ColumnAttribute<'a>,
+}
+
+#[derive(MacrosParameters)]
+#[params(borrow = 'src)]
+pub struct IndexAttribute<'ctx, 'src> {
+    pub name: &'src str,
+    pub fields: &'src TokensObject,
+    pub comment: Option<&'ctx str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use types_reader_core::TokensObject;
+
+    use super::{IndexAttribute, TableAttribute};
+
+    fn parse(src: &str) -> TokensObject {
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        TokensObject::new(token_stream.into()).unwrap()
+    }
+
+    #[test]
+    fn test_struct_with_two_lifetimes() {
+        let tokens_object =
+            parse(r#"name: "users", schema: "public", primary_key(name: "id", comment: "PK")"#);
+        let result: TableAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.name, "users");
+        assert_eq!(result.schema, "public");
+        assert_eq!(result.primary_key.name, "id");
+        assert_eq!(result.primary_key.comment, Some("PK"));
+    }
+
+    #[test]
+    fn test_borrow_lifetime() {
+        let tokens_object = parse(r#"name: "by_email", fields: ["email"], comment: "unique""#);
+        let result: IndexAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.name, "by_email");
+        assert_eq!(result.fields.unwrap_as_vec().unwrap().len(), 1);
+        assert_eq!(result.comment, Some("unique"));
+    }
+}
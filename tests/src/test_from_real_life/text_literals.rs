@@ -0,0 +1,41 @@
+use std::ffi::CString;
+
+use types_reader_core as types_reader;
+use types_reader_macros::MacrosParameters;
+
+#[derive(MacrosParameters)]
+pub struct TextLiterals<'s> {
+    pub pattern: &'s str,
+    pub separator: char,
+    pub magic: Vec<u8>,
+    pub bytes: Vec<u8>,
+    pub name: CString,
+    pub quote: Option<char>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use types_reader_core::TokensObject;
+
+    use super::TextLiterals;
+
+    #[test]
+    fn test_text_literals() {
+        let src = r####"pattern: r#"^\d+\n"#, separator: '\t', magic: b"\x7FELF", bytes: [1, 2], name: c"abc""####;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let tokens_object = TokensObject::new(token_stream.into()).unwrap();
+
+        let result: TextLiterals = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.pattern, r"^\d+\n");
+        assert_eq!(result.separator, '\t');
+        assert_eq!(result.magic, b"\x7FELF".to_vec());
+        assert_eq!(result.bytes, vec![1, 2]);
+        assert_eq!(result.name.as_bytes(), b"abc");
+        assert!(result.quote.is_none());
+    }
+}
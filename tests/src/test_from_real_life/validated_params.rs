@@ -0,0 +1,95 @@
+use types_reader_core as types_reader;
+use types_reader_macros::MacrosParameters;
+
+fn check_name(value: &str) -> Result<(), String> {
+    if value.starts_with('_') {
+        return Err("Name can not start with '_'".to_string());
+    }
+
+    Ok(())
+}
+
+#[derive(MacrosParameters)]
+pub struct ServerAttribute<'s> {
+    #[param(range = 1..=65535)]
+    pub port: u32,
+    #[default]
+    #[param(non_empty, validate = check_name)]
+    pub name: &'s str,
+    #[param(len = 1..=2)]
+    pub hosts: Vec<&'s str>,
+    #[allow_ident]
+    #[param(one_of = [GET, POST])]
+    pub method: Option<&'s str>,
+    #[param(len = 2, default = "en")]
+    pub lang: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use types_reader_core::TokensObject;
+
+    use super::ServerAttribute;
+
+    fn parse(src: &str) -> TokensObject {
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        TokensObject::new(token_stream.into()).unwrap()
+    }
+
+    #[test]
+    fn test_valid_values() {
+        let tokens_object = parse(r#""api", port: 8080, hosts: ["localhost"], method: GET"#);
+        let result: ServerAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.port, 8080);
+        assert_eq!(result.name, "api");
+        assert_eq!(result.hosts, vec!["localhost"]);
+        assert_eq!(result.method, Some("GET"));
+        assert_eq!(result.lang, "en");
+
+        let tokens_object = parse(r#"name: "api", port: 1, hosts: ["a", "b"], lang: "uk""#);
+        let result: ServerAttribute = (&tokens_object).try_into().unwrap();
+
+        assert_eq!(result.method, None);
+        assert_eq!(result.lang, "uk");
+    }
+
+    #[test]
+    fn test_all_invalid_values_are_reported() {
+        let tokens_object = parse(r#""", port: 0, hosts: [], method: PUT, lang: "eng""#);
+
+        let err = TryInto::<ServerAttribute>::try_into(&tokens_object)
+            .err()
+            .unwrap();
+
+        let messages: Vec<String> = err.into_iter().map(|err| err.to_string()).collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "in `port`: Value should be in the range 1..=65535",
+                "in `name`: Value should not be empty",
+                "in `hosts`: Length of the value should be in the range 1..=2. Length is 0",
+                "in `method`: Value should be one of: \"GET\", \"POST\"",
+                "in `lang`: Length of the value should be in the range 2..=2. Length is 3",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_custom_validator() {
+        let tokens_object = parse(r#"name: "_api", port: 80, hosts: ["a"]"#);
+
+        let messages: Vec<String> = TryInto::<ServerAttribute>::try_into(&tokens_object)
+            .err()
+            .unwrap()
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect();
+
+        assert_eq!(messages, vec!["in `name`: Name can not start with '_'"]);
+    }
+}
@@ -1,13 +1,18 @@
 use crate::{
-    BoolValue, DoubleValue, NumberValue, ObjectValue, OptionalObjectValue, StringValue,
-    ValueAsIdent,
+    BoolValue, ByteStringValue, CStringValue, CharValue, DoubleValue, NumberValue, ObjectValue,
+    OptionalObjectValue, PathValue, RawTokensValue, StringValue, TypesReaderError, ValueAsIdent,
 };
 
 #[derive(Clone)]
 pub enum AnyValue<'s> {
     String(&'s StringValue),
+    ByteString(&'s ByteStringValue),
+    Char(&'s CharValue),
+    CString(&'s CStringValue),
     Bool(&'s BoolValue),
     Ident(&'s ValueAsIdent),
+    Path(&'s PathValue),
+    RawTokens(&'s RawTokensValue),
     Number(&'s NumberValue),
     Double(&'s DoubleValue),
     NoValue(&'s syn::Ident),
@@ -28,6 +33,13 @@ impl<'s> AnyValue<'s> {
         }
     }
 
+    pub fn unwrap_as_path(&'s self) -> Result<&'s PathValue, syn::Error> {
+        match self {
+            Self::Path(value) => Ok(value),
+            _ => Err(self.throw_error("Expected Path")),
+        }
+    }
+
     pub fn unwrap_as_number(&'s self) -> Result<&'s NumberValue, syn::Error> {
         match self {
             Self::Number(value) => Ok(value),
@@ -56,10 +68,36 @@ impl<'s> AnyValue<'s> {
         }
     }
 
+    pub fn unwrap_as_byte_string(&'s self) -> Result<&'s ByteStringValue, syn::Error> {
+        match self {
+            Self::ByteString(value) => Ok(value),
+            _ => Err(self.throw_error("Expected byte string value")),
+        }
+    }
+
+    pub fn unwrap_as_char(&'s self) -> Result<&'s CharValue, syn::Error> {
+        match self {
+            Self::Char(value) => Ok(value),
+            _ => Err(self.throw_error("Expected char value")),
+        }
+    }
+
+    pub fn unwrap_as_c_string(&'s self) -> Result<&'s CStringValue, syn::Error> {
+        match self {
+            Self::CString(value) => Ok(value),
+            _ => Err(self.throw_error("Expected C string value")),
+        }
+    }
+
     pub fn unwrap_any_value_as_string(&'s self) -> Result<&'s str, syn::Error> {
         match self {
             Self::String(value) => Ok(value),
+            Self::ByteString(value) => Ok(value.as_str()),
+            Self::Char(value) => Ok(value.as_str()),
+            Self::CString(value) => Ok(value.as_str()),
             Self::Ident(value) => Ok(value.as_str()),
+            Self::Path(value) => Ok(value.as_str()),
+            Self::RawTokens(value) => Ok(value.as_str()),
             Self::Bool(value) => Ok(value.as_str()),
             Self::Double(value) => Ok(value.as_str()),
             Self::Number(value) => Ok(value.as_str()),
@@ -71,8 +109,13 @@ impl<'s> AnyValue<'s> {
         match self {
             Self::NoValue(ident) => syn::Error::new_spanned(ident, message),
             Self::String(value) => value.throw_error(message),
+            Self::ByteString(value) => value.throw_error(message),
+            Self::Char(value) => value.throw_error(message),
+            Self::CString(value) => value.throw_error(message),
             Self::Bool(value) => value.throw_error(message),
             Self::Ident(value) => value.throw_error(message),
+            Self::Path(value) => value.throw_error(message),
+            Self::RawTokens(value) => value.throw_error(message),
             Self::Number(value) => value.throw_error(message),
             Self::Double(value) => value.throw_error(message),
         }
@@ -80,14 +123,15 @@ impl<'s> AnyValue<'s> {
 }
 
 impl<'s> TryInto<AnyValue<'s>> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<AnyValue<'s>, Self::Error> {
         match self {
             OptionalObjectValue::Empty(ident) => Err(syn::Error::new_spanned(
                 ident,
                 "Expected value, found empty token stream",
-            )),
+            )
+            .into()),
             OptionalObjectValue::None(ident) => Ok(AnyValue::NoValue(ident)),
             OptionalObjectValue::SingleValue(value) => value.try_into(),
             OptionalObjectValue::Value { name: _, value } => value.try_into(),
@@ -96,13 +140,18 @@ impl<'s> TryInto<AnyValue<'s>> for &'s OptionalObjectValue {
 }
 
 impl<'s> TryInto<AnyValue<'s>> for &'s ObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<AnyValue<'s>, Self::Error> {
         match self {
             ObjectValue::String(value) => Ok(AnyValue::String(value)),
+            ObjectValue::ByteString(value) => Ok(AnyValue::ByteString(value)),
+            ObjectValue::Char(value) => Ok(AnyValue::Char(value)),
+            ObjectValue::CString(value) => Ok(AnyValue::CString(value)),
             ObjectValue::Bool(value) => Ok(AnyValue::Bool(value)),
             ObjectValue::Ident(value) => Ok(AnyValue::Ident(value)),
+            ObjectValue::Path(value) => Ok(AnyValue::Path(value)),
+            ObjectValue::RawTokens(value) => Ok(AnyValue::RawTokens(value)),
             ObjectValue::Number(value) => Ok(AnyValue::Number(value)),
             ObjectValue::Double(value) => Ok(AnyValue::Double(value)),
         }
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 
-use crate::{OptionalObjectValue, TokensObject};
+use crate::{DuplicateParamPolicy, OptionalObjectValue, TokensObject};
 
 pub trait MacrosAttribute {
     const NAME: &'static str;
@@ -15,7 +15,18 @@ pub struct Attributes<'s> {
 }
 
 impl<'s> Attributes<'s> {
+    /// Attributes of other crates are parsed here too, and some of them legally repeat
+    /// a parameter (E.g.: `#[serde(alias = "a", alias = "b")]`),
+    /// so repeated parameters are collected as a `TokensObject::Vec` instead of failing the derive.
     pub fn new(root: &'s dyn ToTokens, src: &'s [syn::Attribute]) -> Result<Self, syn::Error> {
+        Self::new_with_policy(root, src, DuplicateParamPolicy::CollectAsVec)
+    }
+
+    pub fn new_with_policy(
+        root: &'s dyn ToTokens,
+        src: impl IntoIterator<Item = &'s syn::Attribute>,
+        policy: DuplicateParamPolicy,
+    ) -> Result<Self, syn::Error> {
         let mut attrs = HashMap::new();
 
         for attr in src {
@@ -24,7 +35,7 @@ impl<'s> Attributes<'s> {
             let attr_name_as_str = attr_name.to_string();
 
             let param_list = if let Some(token_stream) = token_stream {
-                TokensObject::new(token_stream.into())?
+                TokensObject::new_with_policy(token_stream.into(), policy)?
             } else {
                 TokensObject::create_empty(attr_name)
             };
@@ -98,7 +109,8 @@ impl<'s> Attributes<'s> {
         param_name: &str,
     ) -> Result<&'s TokensObject, syn::Error> {
         let attr = self.get_attr(attr_name)?;
-        attr.get_named_param(param_name)
+        let value = attr.get_named_param(param_name)?;
+        Ok(value)
     }
 
     pub fn get_single_or_named_param(
@@ -107,7 +119,8 @@ impl<'s> Attributes<'s> {
         param_name: &str,
     ) -> Result<&'s OptionalObjectValue, syn::Error> {
         let attr = self.get_attr(attr_name)?;
-        attr.get_value_from_single_or_named(param_name)
+        let value = attr.get_value_from_single_or_named(param_name)?;
+        Ok(value)
     }
 
     pub fn try_get_single_or_named_param(
@@ -116,7 +129,7 @@ impl<'s> Attributes<'s> {
         param_name: &str,
     ) -> Result<Option<&'s OptionalObjectValue>, syn::Error> {
         match self.try_get_attr(attr_name) {
-            Some(attr) => attr.try_get_value_from_single_or_named(param_name),
+            Some(attr) => Ok(attr.try_get_value_from_single_or_named(param_name)?),
             None => Ok(None),
         }
     }
@@ -365,6 +378,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_repeated_param_of_foreign_attribute_is_collected() {
+        let ast = parse(r#"#[serde(alias = "a", alias = "b")] pub struct A { pub id: u32 }"#);
+
+        let attrs = Attributes::new(&ast, &ast.attrs).unwrap();
+
+        let aliases = attrs
+            .get_named_param("serde", "alias")
+            .unwrap()
+            .unwrap_as_vec()
+            .unwrap();
+
+        assert_eq!(aliases.len(), 2);
+    }
+
     #[test]
     fn test_flag_and_group_attribute_forms_still_work() {
         let ast = parse(
@@ -390,7 +418,8 @@ mod tests {
                 .unwrap()
                 .as_number()
                 .unwrap()
-                .as_i32(),
+                .as_i32()
+                .unwrap(),
             5
         );
 
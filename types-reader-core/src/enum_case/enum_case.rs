@@ -1,4 +1,4 @@
-use crate::{attributes::Attributes, EnumModel, MacrosAttribute, TokensObject};
+use crate::{attributes::Attributes, EnumModel, MacrosAttribute, TokensObject, TypesReaderError};
 
 pub struct EnumCase<'s> {
     pub attrs: Attributes<'s>,
@@ -48,7 +48,7 @@ impl<'s> EnumCase<'s> {
     }
 
     pub fn try_get_attribute<
-        TResult: MacrosAttribute + TryFrom<&'s TokensObject, Error = syn::Error>,
+        TResult: MacrosAttribute + TryFrom<&'s TokensObject, Error = TypesReaderError>,
     >(
         &'s self,
     ) -> Result<Option<TResult>, syn::Error> {
@@ -65,7 +65,7 @@ impl<'s> EnumCase<'s> {
     }
 
     pub fn get_attribute<
-        TResult: MacrosAttribute + TryFrom<&'s TokensObject, Error = syn::Error>,
+        TResult: MacrosAttribute + TryFrom<&'s TokensObject, Error = TypesReaderError>,
     >(
         &'s self,
     ) -> Result<TResult, syn::Error> {
@@ -76,7 +76,7 @@ impl<'s> EnumCase<'s> {
     }
 
     pub fn get_attributes<
-        TResult: MacrosAttribute + TryFrom<&'s TokensObject, Error = syn::Error>,
+        TResult: MacrosAttribute + TryFrom<&'s TokensObject, Error = TypesReaderError>,
     >(
         &'s self,
     ) -> Result<Vec<TResult>, syn::Error> {
@@ -93,7 +93,7 @@ impl<'s> EnumCase<'s> {
     }
 
     pub fn try_get_attributes<
-        TResult: MacrosAttribute + TryFrom<&'s TokensObject, Error = syn::Error>,
+        TResult: MacrosAttribute + TryFrom<&'s TokensObject, Error = TypesReaderError>,
     >(
         &'s self,
     ) -> Result<Option<Vec<TResult>>, syn::Error> {
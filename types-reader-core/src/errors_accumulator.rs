@@ -0,0 +1,50 @@
+use crate::TypesReaderError;
+
+/// Collects every error of the attribute instead of stopping at the first one.
+/// All the collected errors are reported at once as a single combined error.
+#[derive(Debug, Default)]
+pub struct ErrorsAccumulator {
+    error: Option<TypesReaderError>,
+}
+
+impl ErrorsAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, err: impl Into<TypesReaderError>) {
+        match &mut self.error {
+            Some(error) => error.combine(err),
+            None => self.error = Some(err.into()),
+        }
+    }
+
+    /// Returns the value if the result is Ok. Otherwise keeps the error and returns None
+    pub fn handle<T, TError: Into<TypesReaderError>>(
+        &mut self,
+        result: Result<T, TError>,
+    ) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(err) => {
+                self.push(err);
+                None
+            }
+        }
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.error.is_some()
+    }
+
+    pub fn into_result<T>(self, value: T) -> Result<T, TypesReaderError> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(value),
+        }
+    }
+
+    pub fn check(self) -> Result<(), TypesReaderError> {
+        self.into_result(())
+    }
+}
@@ -21,5 +21,13 @@ mod maybe_empty_value;
 pub use maybe_empty_value::*;
 mod any_value;
 pub use any_value::*;
+mod errors_accumulator;
+pub use errors_accumulator::*;
+mod types_reader_error;
+pub use types_reader_error::*;
+mod ordered_map;
+pub use ordered_map::*;
 pub mod utils;
+pub mod validators;
+pub extern crate proc_macro2;
 pub extern crate rust_extensions;
@@ -1,4 +1,4 @@
-use crate::{ObjectValue, OptionalObjectValue};
+use crate::{ObjectValue, OptionalObjectValue, TypesReaderError};
 
 #[derive(Clone)]
 pub enum MaybeEmptyValue<T: Clone> {
@@ -6,7 +6,7 @@ pub enum MaybeEmptyValue<T: Clone> {
     WithValue(T),
 }
 
-impl<'s, T: Clone + TryFrom<&'s ObjectValue, Error = syn::Error>> MaybeEmptyValue<T> {
+impl<'s, T: Clone + TryFrom<&'s ObjectValue, Error = TypesReaderError>> MaybeEmptyValue<T> {
     pub fn is_empty(&self) -> bool {
         match self {
             Self::Empty => true,
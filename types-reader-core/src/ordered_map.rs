@@ -0,0 +1,138 @@
+use std::{borrow::Borrow, collections::HashMap, hash::Hash};
+
+/// Map which keeps the entries in the order they are inserted - the order the parameters are written in.
+/// The named parameters of the `TokensObject::Object`, the `#[param(rest)]` field and the map fields are kept in it
+#[derive(Clone)]
+pub struct OrderedMap<K, V> {
+    items: Vec<(K, V)>,
+    index: HashMap<K, usize>,
+}
+
+impl<K, V> Default for OrderedMap<K, V> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> OrderedMap<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts the entry at the end. If the key is already there - the value is replaced,
+    /// but the entry keeps its position
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(index) = self.index.get(&key) {
+            let (_, item_value) = &mut self.items[*index];
+            return Some(std::mem::replace(item_value, value));
+        }
+
+        self.index.insert(key.clone(), self.items.len());
+        self.items.push((key, value));
+        None
+    }
+
+    pub fn get<Q: Eq + Hash + ?Sized>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = self.index.get(key)?;
+        Some(&self.items[*index].1)
+    }
+
+    pub fn get_mut<Q: Eq + Hash + ?Sized>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = self.index.get(key)?;
+        Some(&mut self.items[*index].1)
+    }
+
+    pub fn contains_key<Q: Eq + Hash + ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.index.contains_key(key)
+    }
+}
+
+impl<K, V> OrderedMap<K, V> {
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.items.iter().map(|(key, value)| (key, value))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.items.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.items.iter().map(|(_, value)| value)
+    }
+}
+
+// The index follows the entries, so only the entries are compared and shown
+impl<K: PartialEq, V: PartialEq> PartialEq for OrderedMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for OrderedMap<K, V> {}
+
+impl<K: std::fmt::Debug, V: std::fmt::Debug> std::fmt::Debug for OrderedMap<K, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> FromIterator<(K, V)> for OrderedMap<K, V> {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut result = Self::new();
+
+        for (key, value) in iter {
+            result.insert(key, value);
+        }
+
+        result
+    }
+}
+
+impl<K, V> IntoIterator for OrderedMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OrderedMap;
+
+    #[test]
+    fn test_insertion_order_is_kept() {
+        let mut map = OrderedMap::new();
+
+        map.insert("zeta".to_string(), 1);
+        map.insert("alpha".to_string(), 2);
+        assert_eq!(map.insert("zeta".to_string(), 3), Some(1));
+
+        let items: Vec<(&String, &i32)> = map.iter().collect();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0], (&"zeta".to_string(), &3));
+        assert_eq!(map.get("alpha"), Some(&2));
+        assert!(!map.contains_key("beta"));
+    }
+}
@@ -13,6 +13,8 @@ pub const U32: &str = "u32";
 pub const I32: &str = "i32";
 pub const U64: &str = "u64";
 pub const I64: &str = "i64";
+pub const U128: &str = "u128";
+pub const I128: &str = "i128";
 pub const F32: &str = "f32";
 pub const F64: &str = "f64";
 pub const U_SIZE: &str = "usize";
@@ -31,6 +33,8 @@ pub enum PropertyType<'s> {
     I32,
     U64,
     I64,
+    U128,
+    I128,
     F32,
     F64,
     USize,
@@ -40,8 +44,10 @@ pub enum PropertyType<'s> {
     DateTime,
     OptionOf(Box<PropertyType<'s>>),
     VecOf(Box<PropertyType<'s>>),
+    ArrayOf(Box<PropertyType<'s>>, &'s syn::Expr),
     Struct(String, &'s TypePath),
     HashMap(Box<PropertyType<'s>>, Box<PropertyType<'s>>),
+    Tuple(Vec<PropertyType<'s>>),
     RefTo {
         ty: Box<PropertyType<'s>>,
         lifetime: Option<&'s syn::Lifetime>,
@@ -56,7 +62,9 @@ impl<'s> PropertyType<'s> {
     pub fn from_ty(ty: &'s syn::Type) -> Self {
         match ty {
             syn::Type::Slice(_) => panic!("Slice type is not supported"),
-            syn::Type::Array(_) => panic!("Array type is not supported"),
+            syn::Type::Array(array) => {
+                Self::ArrayOf(Box::new(Self::from_ty(&array.elem)), &array.len)
+            }
             syn::Type::Ptr(_) => panic!("Ptr type is not supported"),
             syn::Type::Reference(ref_to) => {
                 return Self::RefTo {
@@ -66,7 +74,7 @@ impl<'s> PropertyType<'s> {
             }
             syn::Type::FnPtr(_) => panic!("FnPtr type is not supported"),
             syn::Type::Never(_) => panic!("Never type is not supported"),
-            syn::Type::Tuple(_) => panic!("Tuple type is not supported"),
+            syn::Type::Tuple(tuple) => Self::Tuple(tuple.elems.iter().map(Self::from_ty).collect()),
             syn::Type::Path(type_path) => {
                 let type_as_string = super::utils::simple_type_to_string(type_path);
                 return Self::parse(type_as_string, type_path);
@@ -92,6 +100,8 @@ impl<'s> PropertyType<'s> {
             I32 => PropertyType::I32,
             U64 => PropertyType::U64,
             I64 => PropertyType::I64,
+            U128 => PropertyType::U128,
+            I128 => PropertyType::I128,
             F32 => PropertyType::F32,
             F64 => PropertyType::F64,
             U_SIZE => PropertyType::USize,
@@ -119,6 +129,8 @@ impl<'s> PropertyType<'s> {
             PropertyType::I32 => AsStr::create_as_str(I32),
             PropertyType::U64 => AsStr::create_as_str(U64),
             PropertyType::I64 => AsStr::create_as_str(I64),
+            PropertyType::U128 => AsStr::create_as_str(U128),
+            PropertyType::I128 => AsStr::create_as_str(I128),
             PropertyType::F32 => AsStr::create_as_str(F32),
             PropertyType::F64 => AsStr::create_as_str(F64),
             PropertyType::USize => AsStr::create_as_str(U_SIZE),
@@ -133,9 +145,19 @@ impl<'s> PropertyType<'s> {
             PropertyType::VecOf(generic_type) => {
                 AsStr::create_as_string(format!("Vec::<{}>", generic_type.as_str()))
             }
+            PropertyType::ArrayOf(generic_type, len) => {
+                AsStr::create_as_string(format!("[{}; {}]", generic_type.as_str(), quote!(#len)))
+            }
             PropertyType::HashMap(key, value) => {
                 AsStr::create_as_string(format!("HashMap::<{},{}>", key.as_str(), value.as_str()))
             }
+            PropertyType::Tuple(items) => {
+                let items: Vec<String> = items
+                    .iter()
+                    .map(|item| item.as_str().as_str().to_string())
+                    .collect();
+                AsStr::create_as_string(format!("({})", items.join(", ")))
+            }
             PropertyType::Struct(ty, _) => AsStr::AsStr(ty),
             PropertyType::RefTo { ty, lifetime } => {
                 if let Some(lt) = lifetime {
@@ -157,6 +179,8 @@ impl<'s> PropertyType<'s> {
             PropertyType::I32 => true,
             PropertyType::U64 => true,
             PropertyType::I64 => true,
+            PropertyType::U128 => true,
+            PropertyType::I128 => true,
             PropertyType::F64 => true,
             PropertyType::F32 => true,
             PropertyType::USize => true,
@@ -271,6 +295,22 @@ impl<'s> PropertyType<'s> {
         false
     }
 
+    pub fn is_u128(&self) -> bool {
+        if let PropertyType::U128 = self {
+            return true;
+        }
+
+        false
+    }
+
+    pub fn is_i128(&self) -> bool {
+        if let PropertyType::I128 = self {
+            return true;
+        }
+
+        false
+    }
+
     pub fn is_usize(&self) -> bool {
         if let PropertyType::USize = self {
             return true;
@@ -297,6 +337,8 @@ impl<'s> PropertyType<'s> {
             PropertyType::I32 => quote!(i32),
             PropertyType::U64 => quote!(u64),
             PropertyType::I64 => quote!(i64),
+            PropertyType::U128 => quote!(u128),
+            PropertyType::I128 => quote!(i128),
             PropertyType::F32 => quote!(f32),
             PropertyType::F64 => quote!(f64),
             PropertyType::USize => quote!(usize),
@@ -312,11 +354,19 @@ impl<'s> PropertyType<'s> {
                 let sub_type = sub_type.get_token_stream();
                 quote!(Vec::<#sub_type>)
             }
+            PropertyType::ArrayOf(sub_type, len) => {
+                let sub_type = sub_type.get_token_stream();
+                quote!([#sub_type; #len])
+            }
             PropertyType::HashMap(key, value) => {
                 let key = key.get_token_stream();
                 let value = value.get_token_stream();
                 quote!(HashMap::<#key,#value>)
             }
+            PropertyType::Tuple(items) => {
+                let items = items.iter().map(|item| item.get_token_stream());
+                quote!((#(#items,)*))
+            }
             PropertyType::Struct(name, _) => {
                 let name = proc_macro2::TokenStream::from_str(name).unwrap();
                 quote!(#name)
@@ -342,6 +392,8 @@ impl<'s> PropertyType<'s> {
             PropertyType::I32 => quote!(i32),
             PropertyType::U64 => quote!(u64),
             PropertyType::I64 => quote!(i64),
+            PropertyType::U128 => quote!(u128),
+            PropertyType::I128 => quote!(i128),
             PropertyType::F32 => quote!(f32),
             PropertyType::F64 => quote!(f64),
             PropertyType::USize => quote!(usize),
@@ -357,12 +409,22 @@ impl<'s> PropertyType<'s> {
                 let sub_type = sub_type.get_token_stream_with_generics();
                 quote!(Vec::<#sub_type>)
             }
+            PropertyType::ArrayOf(sub_type, len) => {
+                let sub_type = sub_type.get_token_stream_with_generics();
+                quote!([#sub_type; #len])
+            }
 
             PropertyType::HashMap(key, value) => {
                 let key = key.get_token_stream_with_generics();
                 let value = value.get_token_stream_with_generics();
                 quote!(HashMap::<#key,#value>)
             }
+            PropertyType::Tuple(items) => {
+                let items = items
+                    .iter()
+                    .map(|item| item.get_token_stream_with_generics());
+                quote!((#(#items,)*))
+            }
             PropertyType::Struct(_, ty) => {
                 let mut as_str = quote!(#ty).to_string();
 
@@ -20,11 +20,7 @@ pub fn get_generics(type_path: &syn::TypePath) -> Vec<PropertyType> {
         if let syn::PathArguments::AngleBracketed(args) = &path.arguments {
             for arg in &args.args {
                 if let syn::GenericArgument::Type(ty) = &arg {
-                    if let syn::Type::Path(tp) = ty {
-                        for path in &tp.path.segments {
-                            result.push(PropertyType::parse(path.ident.to_string(), tp));
-                        }
-                    }
+                    result.push(PropertyType::from_ty(ty));
                 }
             }
         }
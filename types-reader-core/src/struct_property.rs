@@ -1,6 +1,6 @@
 use crate::{
     attributes::{Attributes, MacrosAttribute},
-    PropertyType, TokensObject,
+    PropertyType, TokensObject, TypesReaderError,
 };
 
 pub struct StructProperty<'s> {
@@ -53,7 +53,7 @@ impl<'s> StructProperty<'s> {
     }
 
     pub fn try_get_attribute<
-        TResult: MacrosAttribute + TryFrom<&'s TokensObject, Error = syn::Error>,
+        TResult: MacrosAttribute + TryFrom<&'s TokensObject, Error = TypesReaderError>,
     >(
         &'s self,
     ) -> Result<Option<TResult>, syn::Error> {
@@ -70,7 +70,7 @@ impl<'s> StructProperty<'s> {
     }
 
     pub fn get_attribute<
-        TResult: MacrosAttribute + TryFrom<&'s TokensObject, Error = syn::Error>,
+        TResult: MacrosAttribute + TryFrom<&'s TokensObject, Error = TypesReaderError>,
     >(
         &'s self,
     ) -> Result<TResult, syn::Error> {
@@ -81,7 +81,7 @@ impl<'s> StructProperty<'s> {
     }
 
     pub fn get_attributes<
-        TResult: MacrosAttribute + TryFrom<&'s TokensObject, Error = syn::Error>,
+        TResult: MacrosAttribute + TryFrom<&'s TokensObject, Error = TypesReaderError>,
     >(
         &'s self,
     ) -> Result<Vec<TResult>, syn::Error> {
@@ -98,7 +98,7 @@ impl<'s> StructProperty<'s> {
     }
 
     pub fn try_get_attributes<
-        TResult: MacrosAttribute + TryFrom<&'s TokensObject, Error = syn::Error>,
+        TResult: MacrosAttribute + TryFrom<&'s TokensObject, Error = TypesReaderError>,
     >(
         &'s self,
     ) -> Result<Option<Vec<TResult>>, syn::Error> {
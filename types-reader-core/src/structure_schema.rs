@@ -1,8 +1,11 @@
-use crate::{type_name::TypeName, StructProperty};
+use crate::{attributes::Attributes, type_name::TypeName, DuplicateParamPolicy, StructProperty};
 
 pub struct StructureSchema<'s> {
     properties: Vec<StructProperty<'s>>,
     pub name: TypeName,
+    /// `#[params(...)]` attribute of the struct itself.
+    /// Other struct attributes are not parsed: they belong to other crates and may hold content which is not parameters (E.g.: `#[rustfmt::skip]`)
+    pub attrs: Attributes<'s>,
 }
 
 impl<'s> StructureSchema<'s> {
@@ -11,6 +14,13 @@ impl<'s> StructureSchema<'s> {
         let result = Self {
             properties,
             name: TypeName::from_derive_input(data)?,
+            attrs: Attributes::new_with_policy(
+                &data.ident,
+                data.attrs
+                    .iter()
+                    .filter(|attr| attr.path().is_ident("params")),
+                DuplicateParamPolicy::CollectAsVec,
+            )?,
         };
 
         Ok(result)
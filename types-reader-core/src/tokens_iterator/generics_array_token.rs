@@ -2,6 +2,11 @@ use crate::{LifeTimeToken, TokensReader, TokensTreeExt};
 
 pub enum GenericItem {
     LifeTime(LifeTimeToken),
+    Type(syn::Ident),
+    Const {
+        name: syn::Ident,
+        ty: proc_macro2::TokenStream,
+    },
     Raw(proc_macro2::TokenStream),
 }
 
@@ -9,9 +14,19 @@ impl GenericItem {
     pub fn to_token_stream(&self) -> proc_macro2::TokenStream {
         match self {
             GenericItem::LifeTime(life_time) => life_time.to_token_stream(),
+            GenericItem::Type(name) => quote::quote!(#name),
+            GenericItem::Const { name, .. } => quote::quote!(#name),
             GenericItem::Raw(raw) => raw.clone(),
         }
     }
+
+    /// The way the item is declared after `impl`: `const N: usize` instead of `N`
+    pub fn to_declaration_token_stream(&self) -> proc_macro2::TokenStream {
+        match self {
+            GenericItem::Const { name, ty } => quote::quote!(const #name: #ty),
+            _ => self.to_token_stream(),
+        }
+    }
 }
 
 pub struct GenericsArrayToken {
@@ -24,6 +39,35 @@ impl GenericsArrayToken {
             content: Vec::new(),
         }
     }
+    /// Bounds and default values of the parameters are not kept. They are the part of the where clause
+    pub fn from_generics(generics: &syn::Generics) -> Self {
+        let mut content = Vec::new();
+
+        for param in &generics.params {
+            match param {
+                syn::GenericParam::Lifetime(param) => {
+                    content.push(GenericItem::LifeTime(LifeTimeToken::from_lifetime(
+                        &param.lifetime,
+                    )));
+                }
+                syn::GenericParam::Type(param) => {
+                    content.push(GenericItem::Type(param.ident.clone()));
+                }
+                syn::GenericParam::Const(param) => {
+                    content.push(GenericItem::Const {
+                        name: param.ident.clone(),
+                        ty: {
+                            let ty = &param.ty;
+                            quote::quote!(#ty)
+                        },
+                    });
+                }
+            }
+        }
+
+        Self { content }
+    }
+
     pub fn from_tokens_reader(tokens_reader: &mut TokensReader) -> Result<Self, syn::Error> {
         let next_token =
             tokens_reader.get_next_token(None, "Reading First Literal token failed")?;
@@ -46,7 +90,7 @@ impl GenericsArrayToken {
                     tokens_reader.throw_error("Expected next token after open generics bracket")
                 );
             }
-        } else {
+        } else if !next_token.is_ident() {
             return Err(
                 tokens_reader.throw_error("Expected next token after open generics bracket")
             );
@@ -128,6 +172,49 @@ impl GenericsArrayToken {
         quote::quote!(<#(#inners)*>)
     }
 
+    /// Items the way they are declared after `impl`
+    pub fn get_declarations(&self) -> Vec<proc_macro2::TokenStream> {
+        self.content
+            .iter()
+            .map(|itm| itm.to_declaration_token_stream())
+            .collect()
+    }
+
+    pub fn to_declaration_token_stream(&self) -> proc_macro2::TokenStream {
+        let declarations = self.get_declarations();
+
+        if declarations.is_empty() {
+            return quote::quote!();
+        }
+
+        quote::quote!(<#( #declarations ),*>)
+    }
+
+    /// Type parameters. Const generics and lifetimes are not included
+    pub fn get_type_params(&self) -> Vec<&syn::Ident> {
+        let mut result = Vec::new();
+
+        for itm in &self.content {
+            if let GenericItem::Type(name) = itm {
+                result.push(name);
+            }
+        }
+
+        result
+    }
+
+    pub fn get_life_times(&self) -> Vec<&LifeTimeToken> {
+        let mut result = Vec::new();
+
+        for itm in &self.content {
+            if let GenericItem::LifeTime(life_time) = itm {
+                result.push(life_time);
+            }
+        }
+
+        result
+    }
+
     pub fn get_first_life_time(&self) -> Option<&LifeTimeToken> {
         for itm in &self.content {
             if let GenericItem::LifeTime(life_time) = itm {
@@ -196,4 +283,36 @@ mod tests {
 
         assert_eq!("< 'a , MyStructure >", token.to_token_stream().to_string())
     }
+
+    #[test]
+    fn test_generic_with_type_only() {
+        let src = proc_macro2::TokenStream::from_str("<MyStructure>").unwrap();
+        let mut tokens_reader = TokensReader::new(src);
+
+        let token = GenericsArrayToken::from_tokens_reader(&mut tokens_reader).unwrap();
+
+        assert_eq!("< MyStructure >", token.to_token_stream().to_string())
+    }
+
+    #[test]
+    fn test_from_generics() {
+        let generics: syn::Generics =
+            syn::parse_str("<'a, 'b: 'a, T: Copy + Default = u8, const N: usize = 3>").unwrap();
+
+        let token = GenericsArrayToken::from_generics(&generics);
+
+        assert_eq!("< 'a , 'b , T , N >", token.to_token_stream().to_string());
+        assert_eq!(
+            "< 'a , 'b , T , const N : usize >",
+            token.to_declaration_token_stream().to_string()
+        );
+
+        let type_params: Vec<String> = token
+            .get_type_params()
+            .into_iter()
+            .map(|itm| itm.to_string())
+            .collect();
+
+        assert_eq!(type_params, vec!["T"]);
+    }
 }
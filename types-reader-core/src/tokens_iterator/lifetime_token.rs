@@ -27,6 +27,13 @@ impl LifeTimeToken {
         Ok(Self { name, as_string })
     }
 
+    pub fn from_lifetime(life_time: &syn::Lifetime) -> Self {
+        Self {
+            as_string: life_time.ident.to_string(),
+            name: life_time.ident.clone(),
+        }
+    }
+
     pub fn as_str(&self) -> &str {
         &self.as_string
     }
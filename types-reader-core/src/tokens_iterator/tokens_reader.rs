@@ -76,6 +76,32 @@ impl TokensReader {
         }
     }
 
+    pub fn try_peek_token_tree(&self, index: usize) -> Option<&TokenTree> {
+        self.tokens.get(index)
+    }
+
+    pub fn is_next_path_separator(&self) -> bool {
+        match (self.tokens.front(), self.tokens.get(1)) {
+            (Some(TokenTree::Punct(first)), Some(TokenTree::Punct(second))) => {
+                first.as_char() == ':'
+                    && first.spacing() == proc_macro2::Spacing::Joint
+                    && second.as_char() == ':'
+            }
+            _ => false,
+        }
+    }
+
+    /// Reads `::` if it is the next token. Returns `None` and reads nothing otherwise
+    pub fn try_read_path_separator(&mut self) -> Option<TokenStream> {
+        if !self.is_next_path_separator() {
+            return None;
+        }
+
+        let first = self.tokens.pop_front().unwrap();
+        let second = self.tokens.pop_front().unwrap();
+        Some(TokenStream::from_iter([first, second]))
+    }
+
     pub fn read_next_token(&mut self) -> Result<NextToken, syn::Error> {
         let next_token = self.try_read_next_token()?;
         match next_token {
@@ -1,4 +1,4 @@
-use crate::MaybeEmptyValue;
+use crate::{MaybeEmptyValue, TypesReaderError};
 
 pub trait AnyValueAsStr<'s> {
     fn try_as_str(&'s self) -> MaybeEmptyValue<&'s str>;
@@ -7,29 +7,29 @@ pub trait AnyValueAsStr<'s> {
 }
 
 impl<'s> TryInto<&'s str> for &'s dyn AnyValueAsStr<'s> {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<&'s str, Self::Error> {
         match self.try_as_str() {
-            MaybeEmptyValue::Empty => Err(self.throw_error("Value is empty")),
+            MaybeEmptyValue::Empty => Err(self.throw_error("Value is empty").into()),
             MaybeEmptyValue::WithValue(value) => Ok(value),
         }
     }
 }
 
 impl<'s> TryInto<String> for &'s dyn AnyValueAsStr<'s> {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<String, Self::Error> {
         match self.try_as_str() {
-            MaybeEmptyValue::Empty => Err(self.throw_error("Value is empty")),
+            MaybeEmptyValue::Empty => Err(self.throw_error("Value is empty").into()),
             MaybeEmptyValue::WithValue(value) => Ok(value.to_string()),
         }
     }
 }
 
 impl<'s> TryInto<MaybeEmptyValue<&'s str>> for &'s dyn AnyValueAsStr<'s> {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<MaybeEmptyValue<&'s str>, Self::Error> {
         Ok(self.try_as_str())
@@ -37,7 +37,7 @@ impl<'s> TryInto<MaybeEmptyValue<&'s str>> for &'s dyn AnyValueAsStr<'s> {
 }
 
 impl<'s> TryInto<MaybeEmptyValue<String>> for &'s dyn AnyValueAsStr<'s> {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<MaybeEmptyValue<String>, Self::Error> {
         match self.try_as_str() {
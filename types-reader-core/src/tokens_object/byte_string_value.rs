@@ -0,0 +1,82 @@
+use std::ops::Deref;
+
+use proc_macro2::Literal;
+use quote::ToTokens;
+
+use crate::TokenValue;
+#[derive(Debug)]
+pub struct ByteStringValue {
+    literal: TokenValue,
+    value: Vec<u8>,
+    str_value: String,
+    source: String,
+}
+
+impl ByteStringValue {
+    pub fn new(literal: TokenValue, value: Vec<u8>) -> Self {
+        let str_value = String::from_utf8_lossy(&value).into_owned();
+        let source = literal.to_string();
+        Self {
+            literal,
+            value,
+            str_value,
+            source,
+        }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.value.as_slice()
+    }
+
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.value.clone()
+    }
+
+    pub fn into_vec(self) -> Vec<u8> {
+        self.value
+    }
+
+    /// The bytes as text. Bytes which are not UTF-8 are replaced with `U+FFFD`
+    pub fn as_str(&self) -> &str {
+        self.str_value.as_str()
+    }
+
+    /// Source code of the literal. E.g.: `b"abc"`
+    pub fn as_source(&self) -> &str {
+        self.source.as_str()
+    }
+
+    pub fn as_literal(&self) -> &Literal {
+        self.literal.as_literal()
+    }
+
+    pub fn throw_error(&self, message: &str) -> syn::Error {
+        syn::Error::new_spanned(self.as_literal(), message)
+    }
+}
+
+impl<'s> Into<&'s [u8]> for &'s ByteStringValue {
+    fn into(self) -> &'s [u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'s> Into<Vec<u8>> for &'s ByteStringValue {
+    fn into(self) -> Vec<u8> {
+        self.to_vec()
+    }
+}
+
+impl Deref for ByteStringValue {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_bytes()
+    }
+}
+
+impl ToTokens for ByteStringValue {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.literal.as_literal().to_tokens(tokens)
+    }
+}
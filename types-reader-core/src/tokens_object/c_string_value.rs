@@ -0,0 +1,79 @@
+use std::ffi::{CStr, CString};
+use std::ops::Deref;
+
+use proc_macro2::Literal;
+use quote::ToTokens;
+
+use crate::TokenValue;
+#[derive(Debug)]
+pub struct CStringValue {
+    literal: TokenValue,
+    value: CString,
+    str_value: String,
+    source: String,
+}
+
+impl CStringValue {
+    pub fn new(literal: TokenValue, value: CString) -> Self {
+        let str_value = value.to_string_lossy().into_owned();
+        let source = literal.to_string();
+        Self {
+            literal,
+            value,
+            str_value,
+            source,
+        }
+    }
+
+    pub fn as_c_str(&self) -> &CStr {
+        self.value.as_c_str()
+    }
+
+    pub fn to_c_string(&self) -> CString {
+        self.value.clone()
+    }
+
+    /// The string without the trailing nul as text. Bytes which are not UTF-8 are replaced with `U+FFFD`
+    pub fn as_str(&self) -> &str {
+        self.str_value.as_str()
+    }
+
+    /// Source code of the literal. E.g.: `c"abc"`
+    pub fn as_source(&self) -> &str {
+        self.source.as_str()
+    }
+
+    pub fn as_literal(&self) -> &Literal {
+        self.literal.as_literal()
+    }
+
+    pub fn throw_error(&self, message: &str) -> syn::Error {
+        syn::Error::new_spanned(self.as_literal(), message)
+    }
+}
+
+impl<'s> Into<&'s CStr> for &'s CStringValue {
+    fn into(self) -> &'s CStr {
+        self.as_c_str()
+    }
+}
+
+impl<'s> Into<CString> for &'s CStringValue {
+    fn into(self) -> CString {
+        self.to_c_string()
+    }
+}
+
+impl Deref for CStringValue {
+    type Target = CStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_c_str()
+    }
+}
+
+impl ToTokens for CStringValue {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.literal.as_literal().to_tokens(tokens)
+    }
+}
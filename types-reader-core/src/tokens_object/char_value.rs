@@ -0,0 +1,67 @@
+use std::ops::Deref;
+
+use proc_macro2::Literal;
+use quote::ToTokens;
+
+use crate::TokenValue;
+#[derive(Debug)]
+pub struct CharValue {
+    literal: TokenValue,
+    value: char,
+    str_value: String,
+    source: String,
+}
+
+impl CharValue {
+    pub fn new(literal: TokenValue, value: char) -> Self {
+        let source = literal.to_string();
+        Self {
+            literal,
+            value,
+            str_value: value.to_string(),
+            source,
+        }
+    }
+
+    pub fn get_value(&self) -> char {
+        self.value
+    }
+
+    /// The char as a one-character string. E.g.: `\n` is a new line
+    pub fn as_str(&self) -> &str {
+        self.str_value.as_str()
+    }
+
+    /// Source code of the literal. E.g.: `'\n'`
+    pub fn as_source(&self) -> &str {
+        self.source.as_str()
+    }
+
+    pub fn as_literal(&self) -> &Literal {
+        self.literal.as_literal()
+    }
+
+    pub fn throw_error(&self, message: &str) -> syn::Error {
+        syn::Error::new_spanned(self.as_literal(), message)
+    }
+}
+
+impl<'s> Into<char> for &'s CharValue {
+    fn into(self) -> char {
+        self.value
+    }
+}
+
+impl Deref for CharValue {
+    type Target = char;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl ToTokens for CharValue {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.literal.as_literal().to_tokens(tokens)
+    }
+}
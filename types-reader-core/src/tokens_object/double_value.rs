@@ -1,22 +1,53 @@
+use std::fmt::Display;
+
 use proc_macro2::Literal;
+use quote::ToTokens;
+
+use crate::{ErrorKind, TokenValue, TypesReaderError};
 
-use crate::TokenValue;
+/// Float literal in any form Rust accepts: `1.5`, `1e-3`, `2.5E10`, `1_000.5`, `1.0f32`, `-0.5`
 #[derive(Debug)]
 pub struct DoubleValue {
     literal: TokenValue,
     value: f64,
+    digits: String,
+    suffix: String,
     str_value: String,
 }
 
 impl DoubleValue {
-    pub fn new(literal: TokenValue, value: f64, str_value: String) -> Self {
-        Self {
+    pub fn new(literal: TokenValue, lit_float: &syn::LitFloat) -> Result<Self, syn::Error> {
+        let mut digits = lit_float.base10_digits().to_string();
+
+        if literal.is_negative() {
+            digits.insert(0, '-');
+        }
+
+        let value = match digits.parse::<f64>() {
+            Ok(value) => value,
+            Err(_) => {
+                return Err(syn::Error::new_spanned(
+                    literal.to_token_stream(),
+                    "Value can not be parsed as double",
+                ));
+            }
+        };
+
+        let mut str_value = literal.to_string();
+        if literal.is_negative() {
+            str_value.insert(0, '-');
+        }
+
+        Ok(Self {
+            suffix: lit_float.suffix().to_string(),
             literal,
             value,
+            digits,
             str_value,
-        }
+        })
     }
 
+    /// The literal as it is written in the source code, e.g. `1e-3` or `1.0f32`
     pub fn as_str(&self) -> &str {
         self.str_value.as_str()
     }
@@ -25,39 +56,178 @@ impl DoubleValue {
         self.literal.as_literal()
     }
 
-    pub fn as_f64(&self) -> f64 {
-        self.value
+    /// Type suffix of the literal, e.g. `f32` for `1.0f32`
+    pub fn get_suffix(&self) -> Option<&str> {
+        if self.suffix.is_empty() {
+            None
+        } else {
+            Some(self.suffix.as_str())
+        }
+    }
+
+    pub fn as_f64(&self) -> Result<f64, TypesReaderError> {
+        self.check_suffix("f64")?;
+
+        if !self.value.is_finite() {
+            return Err(TypesReaderError::new(
+                ErrorKind::Overflow,
+                self.throw_error(&format!("value {} does not fit into f64", self.str_value)),
+            ));
+        }
+
+        Ok(self.value)
+    }
+
+    pub fn as_f32(&self) -> Result<f32, TypesReaderError> {
+        self.check_suffix("f32")?;
+
+        // Parsing the digits once again gives the closest f32, rounding the f64 twice may not
+        let value = self.digits.parse::<f32>().unwrap_or(self.value as f32);
+
+        if !value.is_finite() {
+            return Err(TypesReaderError::new(
+                ErrorKind::Overflow,
+                self.throw_error(&format!("value {} does not fit into f32", self.str_value)),
+            ));
+        }
+
+        Ok(value)
+    }
+
+    pub fn as_i64(&self) -> Result<i64, TypesReaderError> {
+        self.checked_as("i64", i64::MIN, i64::MAX)
+    }
+
+    pub fn as_u64(&self) -> Result<u64, TypesReaderError> {
+        self.checked_as("u64", u64::MIN, u64::MAX)
+    }
+
+    pub fn as_i32(&self) -> Result<i32, TypesReaderError> {
+        self.checked_as("i32", i32::MIN, i32::MAX)
+    }
+
+    pub fn as_u32(&self) -> Result<u32, TypesReaderError> {
+        self.checked_as("u32", u32::MIN, u32::MAX)
+    }
+
+    pub fn as_i16(&self) -> Result<i16, TypesReaderError> {
+        self.checked_as("i16", i16::MIN, i16::MAX)
+    }
+
+    pub fn as_u16(&self) -> Result<u16, TypesReaderError> {
+        self.checked_as("u16", u16::MIN, u16::MAX)
     }
 
-    pub fn as_f32(&self) -> f32 {
-        self.value as f32
+    pub fn as_i8(&self) -> Result<i8, TypesReaderError> {
+        self.checked_as("i8", i8::MIN, i8::MAX)
     }
 
-    pub fn as_u16(&self) -> u16 {
-        self.value as u16
+    pub fn as_u8(&self) -> Result<u8, TypesReaderError> {
+        self.checked_as("u8", u8::MIN, u8::MAX)
     }
 
-    pub fn as_i8(&self) -> i8 {
-        self.value as i8
+    pub fn as_usize(&self) -> Result<usize, TypesReaderError> {
+        self.checked_as("usize", usize::MIN, usize::MAX)
     }
 
-    pub fn as_u8(&self) -> u8 {
-        self.value as u8
+    pub fn as_isize(&self) -> Result<isize, TypesReaderError> {
+        self.checked_as("isize", isize::MIN, isize::MAX)
     }
 
     pub fn throw_error(&self, message: &str) -> syn::Error {
-        syn::Error::new_spanned(self.as_literal(), message)
+        syn::Error::new_spanned(self.literal.to_token_stream(), message)
+    }
+
+    fn check_suffix(&self, type_name: &str) -> Result<(), TypesReaderError> {
+        if let Some(suffix) = self.get_suffix() {
+            if suffix != type_name {
+                return Err(TypesReaderError::new(
+                    ErrorKind::TypeMismatch {
+                        expected: type_name.to_string(),
+                        found: suffix.to_string(),
+                    },
+                    self.throw_error(&format!(
+                        "value {} has suffix {}, but {} is expected",
+                        self.str_value, suffix, type_name
+                    )),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn checked_as<T: TryFrom<i128> + Display>(
+        &self,
+        type_name: &str,
+        min: T,
+        max: T,
+    ) -> Result<T, TypesReaderError> {
+        // The fractional part of an infinite value is NaN, so it is checked first
+        if !self.value.is_finite() {
+            return Err(self.overflow_error(type_name, &min, &max));
+        }
+
+        if self.value.fract() != 0.0 {
+            return Err(TypesReaderError::new(
+                ErrorKind::TypeMismatch {
+                    expected: type_name.to_string(),
+                    found: "float".to_string(),
+                },
+                self.throw_error(&format!(
+                    "value {} has a fractional part and can not be converted into {}",
+                    self.str_value, type_name
+                )),
+            ));
+        }
+
+        // f64 has 53 bits of mantissa, so every whole f64 in the i128 range converts exactly
+        let value = if self.value.abs() < i128::MAX as f64 {
+            T::try_from(self.value as i128).ok()
+        } else {
+            None
+        };
+
+        match value {
+            Some(value) => Ok(value),
+            None => Err(self.overflow_error(type_name, &min, &max)),
+        }
+    }
+
+    fn overflow_error(
+        &self,
+        type_name: &str,
+        min: &impl Display,
+        max: &impl Display,
+    ) -> TypesReaderError {
+        TypesReaderError::new(
+            ErrorKind::Overflow,
+            self.throw_error(&format!(
+                "value {} does not fit into {} ({}..={})",
+                self.str_value, type_name, min, max
+            )),
+        )
     }
 }
 
-impl<'s> Into<f32> for &'s DoubleValue {
-    fn into(self) -> f32 {
+impl<'s> TryInto<f32> for &'s DoubleValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<f32, Self::Error> {
         self.as_f32()
     }
 }
 
-impl<'s> Into<f64> for &'s DoubleValue {
-    fn into(self) -> f64 {
+impl<'s> TryInto<f64> for &'s DoubleValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<f64, Self::Error> {
         self.as_f64()
     }
 }
+
+impl ToTokens for DoubleValue {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.literal.to_token_stream().to_tokens(tokens)
+    }
+}
@@ -0,0 +1,14 @@
+/// What to do when the same parameter name appears more than once in one object.
+/// E.g.: `#[my_attr(id: 1, id: 2)]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicateParamPolicy {
+    /// Return an error which points at both occurrences
+    #[default]
+    Error,
+    /// Keep the first value and ignore the repeated ones
+    KeepFirst,
+    /// Keep the last value. This is how the parser behaved before the policy was introduced
+    KeepLast,
+    /// Collect all the values of the parameter into a `TokensObject::Vec` in the source order
+    CollectAsVec,
+}
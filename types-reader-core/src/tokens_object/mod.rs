@@ -1,13 +1,23 @@
 mod bool_value;
+mod byte_string_value;
+mod c_string_value;
+mod char_value;
 mod double_value;
 mod number_value;
+mod path_value;
+mod raw_tokens_value;
 //mod objects_list;
 //mod param_value;
 mod string_value;
 mod value_as_ident;
 pub use bool_value::*;
+pub use byte_string_value::*;
+pub use c_string_value::*;
+pub use char_value::*;
 pub use double_value::*;
 pub use number_value::*;
+pub use path_value::*;
+pub use raw_tokens_value::*;
 //pub use objects_list::*;
 //pub use param_value::*;
 pub use string_value::*;
@@ -21,3 +31,7 @@ pub mod token_object_mappers;
 pub use optional_object_value::*;
 mod any_value_as_str;
 pub use any_value_as_str::*;
+mod duplicate_param_policy;
+pub use duplicate_param_policy::*;
+mod object_items;
+pub use object_items::*;
@@ -1,138 +1,429 @@
+use std::fmt::Display;
+
 use proc_macro2::Literal;
 use quote::ToTokens;
 
-use crate::TokenValue;
+use crate::{ErrorKind, TokenValue, TypesReaderError};
+
+/// Integer literal in any form Rust accepts: `255`, `0xFF`, `0o377`, `0b1111_1111`, `255u8`, `-1`
 #[derive(Debug)]
 pub struct NumberValue {
     literal: TokenValue,
-    value: i64,
+    magnitude: u128,
+    is_negative: bool,
+    suffix: String,
     str_value: String,
 }
 
 impl NumberValue {
-    pub fn new(literal: TokenValue, value: i64, str_value: String) -> Self {
-        Self {
+    pub fn new(literal: TokenValue, lit_int: &syn::LitInt) -> Result<Self, syn::Error> {
+        let magnitude = match lit_int.base10_digits().parse::<u128>() {
+            Ok(value) => value,
+            Err(_) => {
+                return Err(syn::Error::new_spanned(
+                    literal.to_token_stream(),
+                    "Integer literal is too large",
+                ));
+            }
+        };
+
+        let is_negative = literal.is_negative();
+
+        let mut str_value = literal.to_string();
+        if is_negative {
+            str_value.insert(0, '-');
+        }
+
+        Ok(Self {
+            suffix: lit_int.suffix().to_string(),
             literal,
-            value,
+            magnitude,
+            is_negative,
             str_value,
-        }
+        })
     }
 
     pub fn as_literal(&self) -> &Literal {
         self.literal.as_literal()
     }
 
+    /// The literal as it is written in the source code, e.g. `0xFFu8` or `-1_000`
     pub fn as_str(&self) -> &str {
         self.str_value.as_str()
     }
 
-    pub fn as_i64(&self) -> i64 {
-        self.value
+    /// Type suffix of the literal, e.g. `u8` for `10u8`
+    pub fn get_suffix(&self) -> Option<&str> {
+        if self.suffix.is_empty() {
+            None
+        } else {
+            Some(self.suffix.as_str())
+        }
     }
 
-    pub fn as_u64(&self) -> u64 {
-        self.value as u64
+    pub fn is_negative(&self) -> bool {
+        self.is_negative
     }
 
-    pub fn as_i32(&self) -> i32 {
-        self.value as i32
+    pub fn as_i128(&self) -> Result<i128, TypesReaderError> {
+        self.checked_as("i128", i128::MIN, i128::MAX)
     }
 
-    pub fn as_u32(&self) -> u32 {
-        self.value as u32
+    pub fn as_u128(&self) -> Result<u128, TypesReaderError> {
+        self.checked_as("u128", u128::MIN, u128::MAX)
     }
 
-    pub fn as_i16(&self) -> i16 {
-        self.value as i16
+    pub fn as_i64(&self) -> Result<i64, TypesReaderError> {
+        self.checked_as("i64", i64::MIN, i64::MAX)
     }
 
-    pub fn as_u16(&self) -> u16 {
-        self.value as u16
+    pub fn as_u64(&self) -> Result<u64, TypesReaderError> {
+        self.checked_as("u64", u64::MIN, u64::MAX)
     }
 
-    pub fn as_i8(&self) -> i8 {
-        self.value as i8
+    pub fn as_i32(&self) -> Result<i32, TypesReaderError> {
+        self.checked_as("i32", i32::MIN, i32::MAX)
     }
 
-    pub fn as_u8(&self) -> u8 {
-        self.value as u8
+    pub fn as_u32(&self) -> Result<u32, TypesReaderError> {
+        self.checked_as("u32", u32::MIN, u32::MAX)
     }
 
-    pub fn as_usize(&self) -> usize {
-        self.value as usize
+    pub fn as_i16(&self) -> Result<i16, TypesReaderError> {
+        self.checked_as("i16", i16::MIN, i16::MAX)
     }
 
-    pub fn as_isize(&self) -> isize {
-        self.value as isize
+    pub fn as_u16(&self) -> Result<u16, TypesReaderError> {
+        self.checked_as("u16", u16::MIN, u16::MAX)
+    }
+
+    pub fn as_i8(&self) -> Result<i8, TypesReaderError> {
+        self.checked_as("i8", i8::MIN, i8::MAX)
+    }
+
+    pub fn as_u8(&self) -> Result<u8, TypesReaderError> {
+        self.checked_as("u8", u8::MIN, u8::MAX)
+    }
+
+    pub fn as_usize(&self) -> Result<usize, TypesReaderError> {
+        self.checked_as("usize", usize::MIN, usize::MAX)
+    }
+
+    pub fn as_isize(&self) -> Result<isize, TypesReaderError> {
+        self.checked_as("isize", isize::MIN, isize::MAX)
+    }
+
+    pub fn wrapping_as_i128(&self) -> i128 {
+        self.wrapping_value() as i128
+    }
+
+    pub fn wrapping_as_u128(&self) -> u128 {
+        self.wrapping_value()
+    }
+
+    pub fn wrapping_as_i64(&self) -> i64 {
+        self.wrapping_value() as i64
+    }
+
+    pub fn wrapping_as_u64(&self) -> u64 {
+        self.wrapping_value() as u64
+    }
+
+    pub fn wrapping_as_i32(&self) -> i32 {
+        self.wrapping_value() as i32
+    }
+
+    pub fn wrapping_as_u32(&self) -> u32 {
+        self.wrapping_value() as u32
+    }
+
+    pub fn wrapping_as_i16(&self) -> i16 {
+        self.wrapping_value() as i16
+    }
+
+    pub fn wrapping_as_u16(&self) -> u16 {
+        self.wrapping_value() as u16
+    }
+
+    pub fn wrapping_as_i8(&self) -> i8 {
+        self.wrapping_value() as i8
+    }
+
+    pub fn wrapping_as_u8(&self) -> u8 {
+        self.wrapping_value() as u8
+    }
+
+    pub fn wrapping_as_usize(&self) -> usize {
+        self.wrapping_value() as usize
+    }
+
+    pub fn wrapping_as_isize(&self) -> isize {
+        self.wrapping_value() as isize
+    }
+
+    pub fn saturating_as_i128(&self) -> i128 {
+        self.saturating_as(i128::MIN, i128::MAX)
+    }
+
+    pub fn saturating_as_u128(&self) -> u128 {
+        self.saturating_as(u128::MIN, u128::MAX)
+    }
+
+    pub fn saturating_as_i64(&self) -> i64 {
+        self.saturating_as(i64::MIN, i64::MAX)
+    }
+
+    pub fn saturating_as_u64(&self) -> u64 {
+        self.saturating_as(u64::MIN, u64::MAX)
+    }
+
+    pub fn saturating_as_i32(&self) -> i32 {
+        self.saturating_as(i32::MIN, i32::MAX)
+    }
+
+    pub fn saturating_as_u32(&self) -> u32 {
+        self.saturating_as(u32::MIN, u32::MAX)
+    }
+
+    pub fn saturating_as_i16(&self) -> i16 {
+        self.saturating_as(i16::MIN, i16::MAX)
+    }
+
+    pub fn saturating_as_u16(&self) -> u16 {
+        self.saturating_as(u16::MIN, u16::MAX)
+    }
+
+    pub fn saturating_as_i8(&self) -> i8 {
+        self.saturating_as(i8::MIN, i8::MAX)
+    }
+
+    pub fn saturating_as_u8(&self) -> u8 {
+        self.saturating_as(u8::MIN, u8::MAX)
+    }
+
+    pub fn saturating_as_usize(&self) -> usize {
+        self.saturating_as(usize::MIN, usize::MAX)
+    }
+
+    pub fn saturating_as_isize(&self) -> isize {
+        self.saturating_as(isize::MIN, isize::MAX)
+    }
+
+    /// Integer literal used where a float is expected. Works only if the value is exactly representable
+    pub fn as_f64(&self) -> Result<f64, TypesReaderError> {
+        self.check_suffix("f64")?;
+
+        let value = self.magnitude as f64;
+        self.check_exact_float(value, "f64")?;
+
+        if self.is_negative {
+            Ok(-value)
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// Integer literal used where a float is expected. Works only if the value is exactly representable
+    pub fn as_f32(&self) -> Result<f32, TypesReaderError> {
+        self.check_suffix("f32")?;
+
+        let value = self.magnitude as f32;
+        self.check_exact_float(value as f64, "f32")?;
+
+        if self.is_negative {
+            Ok(-value)
+        } else {
+            Ok(value)
+        }
     }
 
     pub fn throw_error(&self, message: &str) -> syn::Error {
-        syn::Error::new_spanned(self.as_literal(), message)
+        syn::Error::new_spanned(self.literal.to_token_stream(), message)
+    }
+
+    fn try_as<T: TryFrom<u128> + TryFrom<i128>>(&self) -> Option<T> {
+        if self.is_negative {
+            let value = 0i128.checked_sub_unsigned(self.magnitude)?;
+            T::try_from(value).ok()
+        } else {
+            T::try_from(self.magnitude).ok()
+        }
+    }
+
+    fn checked_as<T: TryFrom<u128> + TryFrom<i128> + Display>(
+        &self,
+        type_name: &str,
+        min: T,
+        max: T,
+    ) -> Result<T, TypesReaderError> {
+        self.check_suffix(type_name)?;
+
+        match self.try_as() {
+            Some(value) => Ok(value),
+            None => Err(TypesReaderError::new(
+                ErrorKind::Overflow,
+                self.throw_error(&format!(
+                    "value {} does not fit into {} ({}..={})",
+                    self.str_value, type_name, min, max
+                )),
+            )),
+        }
+    }
+
+    fn check_suffix(&self, type_name: &str) -> Result<(), TypesReaderError> {
+        if let Some(suffix) = self.get_suffix() {
+            if suffix != type_name {
+                return Err(TypesReaderError::new(
+                    ErrorKind::TypeMismatch {
+                        expected: type_name.to_string(),
+                        found: suffix.to_string(),
+                    },
+                    self.throw_error(&format!(
+                        "value {} has suffix {}, but {} is expected",
+                        self.str_value, suffix, type_name
+                    )),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_exact_float(&self, value: f64, type_name: &str) -> Result<(), TypesReaderError> {
+        // u128::MAX rounds up to 2^128, which `as u128` would saturate back to u128::MAX
+        if value < 2f64.powi(128) && value as u128 == self.magnitude {
+            return Ok(());
+        }
+
+        Err(TypesReaderError::new(
+            ErrorKind::Overflow,
+            self.throw_error(&format!(
+                "value {} can not be represented exactly as {}",
+                self.str_value, type_name
+            )),
+        ))
+    }
+
+    fn wrapping_value(&self) -> u128 {
+        if self.is_negative {
+            self.magnitude.wrapping_neg()
+        } else {
+            self.magnitude
+        }
+    }
+
+    fn saturating_as<T: TryFrom<u128> + TryFrom<i128>>(&self, min: T, max: T) -> T {
+        match self.try_as() {
+            Some(value) => value,
+            None => {
+                if self.is_negative {
+                    min
+                } else {
+                    max
+                }
+            }
+        }
     }
 }
 
-impl<'s> Into<u8> for &'s NumberValue {
-    fn into(self) -> u8 {
+impl<'s> TryInto<u8> for &'s NumberValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<u8, Self::Error> {
         self.as_u8()
     }
 }
 
-impl<'s> Into<i8> for &'s NumberValue {
-    fn into(self) -> i8 {
+impl<'s> TryInto<i8> for &'s NumberValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<i8, Self::Error> {
         self.as_i8()
     }
 }
 
-impl<'s> Into<u16> for &'s NumberValue {
-    fn into(self) -> u16 {
+impl<'s> TryInto<u16> for &'s NumberValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<u16, Self::Error> {
         self.as_u16()
     }
 }
 
-impl<'s> Into<i16> for &'s NumberValue {
-    fn into(self) -> i16 {
+impl<'s> TryInto<i16> for &'s NumberValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<i16, Self::Error> {
         self.as_i16()
     }
 }
 
-impl<'s> Into<u32> for &'s NumberValue {
-    fn into(self) -> u32 {
+impl<'s> TryInto<u32> for &'s NumberValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<u32, Self::Error> {
         self.as_u32()
     }
 }
 
-impl<'s> Into<i32> for &'s NumberValue {
-    fn into(self) -> i32 {
+impl<'s> TryInto<i32> for &'s NumberValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<i32, Self::Error> {
         self.as_i32()
     }
 }
 
-impl<'s> Into<u64> for &'s NumberValue {
-    fn into(self) -> u64 {
+impl<'s> TryInto<u64> for &'s NumberValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<u64, Self::Error> {
         self.as_u64()
     }
 }
 
-impl<'s> Into<i64> for &'s NumberValue {
-    fn into(self) -> i64 {
+impl<'s> TryInto<i64> for &'s NumberValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<i64, Self::Error> {
         self.as_i64()
     }
 }
 
-impl<'s> Into<usize> for &'s NumberValue {
-    fn into(self) -> usize {
+impl<'s> TryInto<u128> for &'s NumberValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<u128, Self::Error> {
+        self.as_u128()
+    }
+}
+
+impl<'s> TryInto<i128> for &'s NumberValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<i128, Self::Error> {
+        self.as_i128()
+    }
+}
+
+impl<'s> TryInto<usize> for &'s NumberValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<usize, Self::Error> {
         self.as_usize()
     }
 }
 
-impl<'s> Into<isize> for &'s NumberValue {
-    fn into(self) -> isize {
+impl<'s> TryInto<isize> for &'s NumberValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<isize, Self::Error> {
         self.as_isize()
     }
 }
 
 impl ToTokens for NumberValue {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        self.value.to_tokens(tokens)
+        self.literal.to_token_stream().to_tokens(tokens)
     }
 }
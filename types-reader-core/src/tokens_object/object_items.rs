@@ -0,0 +1,200 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+
+use crate::{OrderedMap, TokensObject};
+
+/// Named parameter of the `TokensObject::Object`.
+/// The name of the key may differ from its ident: `r#type` is named `type`,
+/// and the ident of a kebab-case key `content-type` is its first word.
+#[derive(Debug)]
+pub struct ObjectItem {
+    pub ident: syn::Ident,
+    /// Tokens of the whole key, so errors point at the whole `content-type`
+    pub key_tokens: TokenStream,
+    pub value: TokensObject,
+}
+
+/// Named parameters of the `TokensObject::Object` by their names, in the order they are written in.
+#[derive(Debug, Default)]
+pub struct ObjectItems {
+    items: OrderedMap<String, ObjectItem>,
+    repeated_keys: Vec<(String, TokenStream)>,
+}
+
+impl ObjectItems {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts the parameter at the end. If the parameter is already there -
+    /// the key and the value are replaced, but the parameter keeps its position.
+    pub fn insert(&mut self, key: syn::Ident, value: TokensObject) -> Option<TokensObject> {
+        self.insert_with_name(key.to_string(), key, value)
+    }
+
+    pub fn insert_with_name(
+        &mut self,
+        name: String,
+        key: syn::Ident,
+        value: TokensObject,
+    ) -> Option<TokensObject> {
+        let key_tokens = key.to_token_stream();
+        self.insert_with_key_tokens(name, key, key_tokens, value)
+    }
+
+    /// `key_tokens` - all the tokens of the key: `content`, `-`, `type` for a kebab-case key
+    pub fn insert_with_key_tokens(
+        &mut self,
+        name: String,
+        key: syn::Ident,
+        key_tokens: TokenStream,
+        value: TokensObject,
+    ) -> Option<TokensObject> {
+        let item = ObjectItem {
+            ident: key,
+            key_tokens,
+            value,
+        };
+
+        self.items.insert(name, item).map(|item| item.value)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&TokensObject> {
+        self.items.get(name).map(|item| &item.value)
+    }
+
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut TokensObject> {
+        self.items.get_mut(name).map(|item| &mut item.value)
+    }
+
+    pub fn get_key_ident(&self, name: &str) -> Option<&syn::Ident> {
+        self.items.get(name).map(|item| &item.ident)
+    }
+
+    /// Tokens of the whole key. Use them to span an error at the key
+    pub fn get_key_tokens(&self, name: &str) -> Option<&TokenStream> {
+        self.items.get(name).map(|item| &item.key_tokens)
+    }
+
+    pub fn contains_key(&self, name: &str) -> bool {
+        self.items.contains_key(name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Names and parameters in the order they are written in
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ObjectItem)> {
+        self.items.iter().map(|(name, item)| (name.as_str(), item))
+    }
+
+    pub fn iter_in_source_order(&self) -> impl Iterator<Item = (&syn::Ident, &TokensObject)> {
+        self.items.values().map(|item| (&item.ident, &item.value))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &syn::Ident> {
+        self.items.values().map(|item| &item.ident)
+    }
+
+    /// Names of the parameters in the order they are written in
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.items.keys().map(|name| name.as_str())
+    }
+
+    /// Keys which are written once more and whose values are collected with `DuplicateParamPolicy::CollectAsVec`.
+    /// The first occurrence of the key is not in the list
+    pub fn repeated_keys(&self) -> impl Iterator<Item = (&str, &TokenStream)> {
+        self.repeated_keys
+            .iter()
+            .map(|(name, key_tokens)| (name.as_str(), key_tokens))
+    }
+
+    pub(crate) fn add_repeated_key(&mut self, name: String, key_tokens: TokenStream) {
+        self.repeated_keys.push((name, key_tokens));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::TokensObject;
+
+    #[test]
+    fn test_params_are_iterated_in_source_order() {
+        let src = r#"zeta: 1, alpha: 2, mu: 3, beta(a: 1), gamma"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let names: Vec<String> = params_list
+            .unwrap_as_object()
+            .iter_in_source_order()
+            .map(|(key, _)| key.to_string())
+            .collect();
+
+        assert_eq!(names, vec!["zeta", "alpha", "mu", "beta", "gamma"]);
+    }
+
+    #[test]
+    fn test_key_ident_is_kept() {
+        let src = r#"first: 1, second: "value""#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let key = params_list.get_key_ident("second").unwrap();
+
+        assert_eq!(key.to_string(), "second");
+        assert!(params_list.get_key_ident("third").is_none());
+    }
+
+    #[test]
+    fn test_raw_and_kebab_case_keys() {
+        let src = r#"r#type: "text", content-type: "json", max-body-size: 10"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let names: Vec<&str> = params_list.unwrap_as_object().names().collect();
+
+        assert_eq!(names, vec!["type", "content-type", "max-body-size"]);
+
+        let value: &str = params_list
+            .get_named_param("content-type")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(value, "json");
+
+        let value: u32 = params_list
+            .get_named_param("max-body-size")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(value, 10);
+
+        let key = params_list.get_key_ident("content-type").unwrap();
+        assert_eq!(key.to_string(), "content");
+
+        let key_tokens = params_list
+            .unwrap_as_object()
+            .get_key_tokens("content-type")
+            .unwrap();
+        assert_eq!(key_tokens.to_string(), "content - type");
+
+        let key_tokens = params_list
+            .unwrap_as_object()
+            .get_key_tokens("type")
+            .unwrap();
+        assert_eq!(key_tokens.to_string(), "r#type");
+    }
+}
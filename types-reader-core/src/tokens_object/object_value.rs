@@ -1,32 +1,73 @@
+use std::ffi::{CStr, CString};
 use std::str::FromStr;
 
+use quote::ToTokens;
 use rust_extensions::StrOrString;
 
-use crate::{BoolValue, DoubleValue, NumberValue, StringValue, TokenValue, ValueAsIdent};
+use crate::{
+    BoolValue, ByteStringValue, CStringValue, CharValue, DoubleValue, ErrorKind, NumberValue,
+    PathValue, RawTokensValue, StringValue, TokenValue, TypesReaderError, ValueAsIdent,
+};
 
 #[derive(Debug)]
 pub enum ObjectValue {
     Ident(ValueAsIdent),
+    Path(PathValue),
     String(StringValue),
+    ByteString(ByteStringValue),
+    Char(CharValue),
+    CString(CStringValue),
     Number(NumberValue),
     Double(DoubleValue),
     Bool(BoolValue),
+    RawTokens(RawTokensValue),
 }
 
 impl ObjectValue {
     pub fn throw_error(&self, message: &str) -> syn::Error {
         match self {
             Self::Ident(value) => value.throw_error(message),
+            Self::Path(value) => value.throw_error(message),
             Self::String(value) => value.throw_error(message),
+            Self::ByteString(value) => value.throw_error(message),
+            Self::Char(value) => value.throw_error(message),
+            Self::CString(value) => value.throw_error(message),
             Self::Number(value) => value.throw_error(message),
             Self::Double(value) => value.throw_error(message),
             Self::Bool(value) => value.throw_error(message),
+            Self::RawTokens(value) => value.throw_error(message),
         }
     }
-    pub fn as_string<'s>(&'s self) -> Result<&'s StringValue, syn::Error> {
+    /// Name of the value type as it is used in the error messages. E.g.: `string`, `number`
+    pub fn get_type_name(&self) -> &'static str {
+        match self {
+            Self::Ident(_) => "ident",
+            Self::Path(_) => "path",
+            Self::String(_) => "string",
+            Self::ByteString(_) => "byte string",
+            Self::Char(_) => "char",
+            Self::CString(_) => "C string",
+            Self::Number(_) => "number",
+            Self::Double(_) => "float",
+            Self::Bool(_) => "bool",
+            Self::RawTokens(_) => "raw tokens",
+        }
+    }
+
+    fn type_mismatch_error(&self, expected: &str, message: &str) -> TypesReaderError {
+        TypesReaderError::new(
+            ErrorKind::TypeMismatch {
+                expected: expected.to_string(),
+                found: self.get_type_name().to_string(),
+            },
+            self.throw_error(message),
+        )
+    }
+
+    pub fn as_string<'s>(&'s self) -> Result<&'s StringValue, TypesReaderError> {
         match self.try_as_string() {
             Some(value) => Ok(value),
-            _ => Err(self.throw_error("Type should be a string")),
+            _ => Err(self.type_mismatch_error("string", "Type should be a string")),
         }
     }
 
@@ -37,10 +78,70 @@ impl ObjectValue {
         }
     }
 
-    pub fn as_bool(&self) -> Result<&BoolValue, syn::Error> {
+    pub fn as_byte_string(&self) -> Result<&ByteStringValue, TypesReaderError> {
+        match self.try_as_byte_string() {
+            Some(value) => Ok(value),
+            _ => Err(self.type_mismatch_error("byte string", "Type should be a byte string")),
+        }
+    }
+
+    pub fn try_as_byte_string(&self) -> Option<&ByteStringValue> {
+        match self {
+            Self::ByteString(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_char(&self) -> Result<&CharValue, TypesReaderError> {
+        match self.try_as_char() {
+            Some(value) => Ok(value),
+            _ => Err(self.type_mismatch_error("char", "Type should be a char")),
+        }
+    }
+
+    pub fn try_as_char(&self) -> Option<&CharValue> {
+        match self {
+            Self::Char(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_c_string(&self) -> Result<&CStringValue, TypesReaderError> {
+        match self.try_as_c_string() {
+            Some(value) => Ok(value),
+            _ => Err(self.type_mismatch_error("C string", "Type should be a C string")),
+        }
+    }
+
+    pub fn try_as_c_string(&self) -> Option<&CStringValue> {
+        match self {
+            Self::CString(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Reads a float. Integer literals are accepted if the value is exactly representable as `f64`
+    pub fn as_f64(&self) -> Result<f64, TypesReaderError> {
+        match self {
+            Self::Double(value) => value.as_f64(),
+            Self::Number(value) => value.as_f64(),
+            _ => Err(self.type_mismatch_error("float", "Type should be a float")),
+        }
+    }
+
+    /// Reads a float. Integer literals are accepted if the value is exactly representable as `f32`
+    pub fn as_f32(&self) -> Result<f32, TypesReaderError> {
+        match self {
+            Self::Double(value) => value.as_f32(),
+            Self::Number(value) => value.as_f32(),
+            _ => Err(self.type_mismatch_error("float", "Type should be a float")),
+        }
+    }
+
+    pub fn as_bool(&self) -> Result<&BoolValue, TypesReaderError> {
         match self.try_as_bool() {
             Some(value) => Ok(value),
-            _ => Err(self.throw_error("Type should be bool")),
+            _ => Err(self.type_mismatch_error("bool", "Type should be bool")),
         }
     }
 
@@ -57,17 +158,78 @@ impl ObjectValue {
             _ => None,
         }
     }
-    pub fn as_ident(&self) -> Result<&ValueAsIdent, syn::Error> {
+    pub fn as_ident(&self) -> Result<&ValueAsIdent, TypesReaderError> {
         match self.try_as_ident() {
             Some(value) => Ok(value),
-            _ => Err(self.throw_error("Type should be ident")),
+            _ => Err(self.type_mismatch_error("ident", "Type should be ident")),
+        }
+    }
+
+    pub fn try_as_raw_tokens(&self) -> Option<&RawTokensValue> {
+        match self {
+            Self::RawTokens(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_raw_tokens(&self) -> Result<&RawTokensValue, TypesReaderError> {
+        match self.try_as_raw_tokens() {
+            Some(value) => Ok(value),
+            _ => {
+                Err(self
+                    .type_mismatch_error("raw tokens", "Type should be a type or an expression"))
+            }
+        }
+    }
+
+    /// Tokens of the value as they are written in the source code
+    pub fn to_token_stream(&self) -> proc_macro2::TokenStream {
+        match self {
+            Self::Ident(value) => value.get_ident().to_token_stream(),
+            Self::Path(value) => value.to_token_stream(),
+            Self::String(value) => value.as_literal().to_token_stream(),
+            Self::ByteString(value) => value.as_literal().to_token_stream(),
+            Self::Char(value) => value.as_literal().to_token_stream(),
+            Self::CString(value) => value.as_literal().to_token_stream(),
+            Self::Number(value) => value.to_token_stream(),
+            Self::Double(value) => value.to_token_stream(),
+            Self::Bool(value) => value.as_ident().to_token_stream(),
+            Self::RawTokens(value) => value.to_token_stream(),
         }
     }
 
-    pub fn as_number(&self) -> Result<&NumberValue, syn::Error> {
+    /// Parses the tokens of any value as Rust syntax. E.g.: `syn::Type` or `syn::Expr`
+    pub fn parse_as<T: syn::parse::Parse>(&self) -> Result<T, syn::Error> {
+        syn::parse2(self.to_token_stream())
+    }
+
+    pub fn try_as_path(&self) -> Option<&PathValue> {
+        match self {
+            Self::Path(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_path(&self) -> Result<&PathValue, TypesReaderError> {
+        match self.try_as_path() {
+            Some(value) => Ok(value),
+            _ => Err(self.type_mismatch_error("path", "Type should be a path")),
+        }
+    }
+
+    /// Reads the value as a path. A single ident is a path with one segment
+    pub fn get_path(&self) -> Result<&syn::Path, TypesReaderError> {
+        match self {
+            Self::Path(value) => Ok(value.get_path()),
+            Self::Ident(value) => Ok(value.get_path()),
+            _ => Err(self.type_mismatch_error("path", "Type should be a path")),
+        }
+    }
+
+    pub fn as_number(&self) -> Result<&NumberValue, TypesReaderError> {
         match self.try_as_number() {
             Some(value) => Ok(value),
-            _ => Err(self.throw_error("Value should be a number")),
+            _ => Err(self.type_mismatch_error("number", "Value should be a number")),
         }
     }
 
@@ -78,10 +240,10 @@ impl ObjectValue {
         }
     }
 
-    pub fn as_double(&self) -> Result<&DoubleValue, syn::Error> {
+    pub fn as_double(&self) -> Result<&DoubleValue, TypesReaderError> {
         match self.try_as_double() {
             Some(value) => Ok(value),
-            _ => Err(self.throw_error("Type should be a double value")),
+            _ => Err(self.type_mismatch_error("float", "Type should be a double value")),
         }
     }
 
@@ -103,10 +265,15 @@ impl ObjectValue {
     ) -> Result<TResult, syn::Error> {
         let value = match self {
             Self::String(value) => StrOrString::create_as_str(value.as_str()),
+            Self::ByteString(value) => StrOrString::create_as_str(value.as_str()),
+            Self::Char(value) => StrOrString::create_as_str(value.as_str()),
+            Self::CString(value) => StrOrString::create_as_str(value.as_str()),
             Self::Number(value) => StrOrString::create_as_str(value.as_str()),
             Self::Double(value) => StrOrString::create_as_str(value.as_str()),
             Self::Bool(value) => StrOrString::create_as_str(value.as_str()),
             Self::Ident(value) => StrOrString::create_as_str(value.as_str()),
+            Self::Path(value) => StrOrString::create_as_str(value.as_str()),
+            Self::RawTokens(value) => StrOrString::create_as_str(value.as_str()),
         };
 
         let value = value.as_str();
@@ -128,10 +295,15 @@ impl ObjectValue {
     pub fn any_value_as_str(&self) -> &str {
         match self {
             Self::String(value) => value.as_str(),
+            Self::ByteString(value) => value.as_str(),
+            Self::Char(value) => value.as_str(),
+            Self::CString(value) => value.as_str(),
             Self::Number(value) => value.as_str(),
             Self::Double(value) => value.as_str(),
             Self::Bool(value) => value.as_str(),
             Self::Ident(value) => value.as_str(),
+            Self::Path(value) => value.as_str(),
+            Self::RawTokens(value) => value.as_str(),
         }
     }
 
@@ -169,55 +341,35 @@ impl TryInto<ObjectValue> for TokenValue {
 
     fn try_into(self) -> Result<ObjectValue, Self::Error> {
         let is_negative = self.is_negative();
-        let mut value = self.to_string();
-
-        if value.starts_with('"') || value.starts_with("'") {
-            let value = value[1..value.len() - 1].to_string();
-            return Ok(ObjectValue::String(StringValue::new(self, value)));
-        }
-
-        if value.contains('.') {
-            let result = value.parse::<f64>();
-            match result {
-                Ok(mut double_value) => {
-                    if is_negative {
-                        double_value = -double_value;
-                        value.insert(0, '-');
-                    }
-                    return Ok(ObjectValue::Double(DoubleValue::new(
-                        self,
-                        double_value,
-                        value,
-                    )));
-                }
-                Err(_) => {
-                    return Err(syn::Error::new_spanned(
-                        self.as_literal(),
-                        "Value can not be parsed as double",
-                    ));
-                }
-            }
+
+        let literal = syn::Lit::new(self.as_literal().clone());
+
+        if is_negative && !matches!(literal, syn::Lit::Int(_) | syn::Lit::Float(_)) {
+            return Err(syn::Error::new_spanned(
+                self.to_token_stream(),
+                "Only numbers can be negative",
+            ));
         }
 
-        match value.parse::<i64>() {
-            Ok(mut i64_value) => {
-                if is_negative {
-                    i64_value = -i64_value;
-                    value.insert(0, '-');
-                }
-                return Ok(ObjectValue::Number(NumberValue::new(
-                    self, i64_value, value,
-                )));
-            }
-            Err(_) => {
-                return Err(syn::Error::new_spanned(self.as_literal(), "Unknown type"));
+        match literal {
+            syn::Lit::Str(value) => Ok(ObjectValue::String(StringValue::new(self, value.value()))),
+            syn::Lit::ByteStr(value) => Ok(ObjectValue::ByteString(ByteStringValue::new(
+                self,
+                value.value(),
+            ))),
+            syn::Lit::Char(value) => Ok(ObjectValue::Char(CharValue::new(self, value.value()))),
+            syn::Lit::CStr(value) => {
+                Ok(ObjectValue::CString(CStringValue::new(self, value.value())))
             }
+            syn::Lit::Int(value) => Ok(ObjectValue::Number(NumberValue::new(self, &value)?)),
+            syn::Lit::Float(value) => Ok(ObjectValue::Double(DoubleValue::new(self, &value)?)),
+            _ => Err(syn::Error::new_spanned(self.as_literal(), "Unknown type")),
         }
     }
 }
 
 impl<'s> TryFrom<&'s ObjectValue> for &'s str {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_from(value: &'s ObjectValue) -> Result<&'s str, Self::Error> {
         let value = value.as_string()?.as_str();
@@ -226,7 +378,7 @@ impl<'s> TryFrom<&'s ObjectValue> for &'s str {
 }
 
 impl<'s> TryFrom<&'s ObjectValue> for String {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_from(value: &'s ObjectValue) -> Result<String, Self::Error> {
         let value = value.as_string()?.as_str();
@@ -234,110 +386,227 @@ impl<'s> TryFrom<&'s ObjectValue> for String {
     }
 }
 
+impl<'s> TryInto<&'s syn::Path> for &'s ObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<&'s syn::Path, Self::Error> {
+        let value = self.get_path()?;
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<syn::Path> for &'s ObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<syn::Path, Self::Error> {
+        let value = self.get_path()?.clone();
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<syn::Ident> for &'s ObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<syn::Ident, Self::Error> {
+        match self {
+            ObjectValue::Ident(value) => Ok(value.get_ident().clone()),
+            ObjectValue::Path(value) => Err(value.throw_error("Expected ident, found path").into()),
+            _ => Err(self.throw_error("Type should be ident").into()),
+        }
+    }
+}
+
+impl<'s> TryInto<syn::Type> for &'s ObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<syn::Type, Self::Error> {
+        Ok(self.parse_as()?)
+    }
+}
+
+impl<'s> TryInto<syn::Expr> for &'s ObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<syn::Expr, Self::Error> {
+        Ok(self.parse_as()?)
+    }
+}
+
+impl<'s> TryInto<syn::ExprClosure> for &'s ObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<syn::ExprClosure, Self::Error> {
+        Ok(self.parse_as()?)
+    }
+}
+
+impl<'s> TryInto<char> for &'s ObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<char, Self::Error> {
+        let value = self.as_char()?.get_value();
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<&'s [u8]> for &'s ObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<&'s [u8], Self::Error> {
+        let value = self.as_byte_string()?.as_bytes();
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<Vec<u8>> for &'s ObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<Vec<u8>, Self::Error> {
+        let value = self.as_byte_string()?.to_vec();
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<&'s CStr> for &'s ObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<&'s CStr, Self::Error> {
+        let value = self.as_c_string()?.as_c_str();
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<CString> for &'s ObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<CString, Self::Error> {
+        let value = self.as_c_string()?.to_c_string();
+        Ok(value)
+    }
+}
+
 impl<'s> TryInto<i8> for &'s ObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<i8, Self::Error> {
-        let value = self.as_number()?.as_i8();
+        let value = self.as_number()?.as_i8()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<u8> for &'s ObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<u8, Self::Error> {
-        let value = self.as_number()?.as_u8();
+        let value = self.as_number()?.as_u8()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<i16> for &'s ObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<i16, Self::Error> {
-        let value = self.as_number()?.as_i16();
+        let value = self.as_number()?.as_i16()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<u16> for &'s ObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<u16, Self::Error> {
-        let value = self.as_number()?.as_u16();
+        let value = self.as_number()?.as_u16()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<i32> for &'s ObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<i32, Self::Error> {
-        let value = self.as_number()?.as_i32();
+        let value = self.as_number()?.as_i32()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<u32> for &'s ObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<u32, Self::Error> {
-        let value = self.as_number()?.as_u32();
+        let value = self.as_number()?.as_u32()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<i64> for &'s ObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<i64, Self::Error> {
-        let value = self.as_number()?.as_i64();
+        let value = self.as_number()?.as_i64()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<u64> for &'s ObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<u64, Self::Error> {
-        let value = self.as_number()?.as_u64();
+        let value = self.as_number()?.as_u64()?;
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<u128> for &'s ObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<u128, Self::Error> {
+        let value = self.as_number()?.as_u128()?;
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<i128> for &'s ObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<i128, Self::Error> {
+        let value = self.as_number()?.as_i128()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<isize> for &'s ObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<isize, Self::Error> {
-        let value = self.as_number()?.as_isize();
+        let value = self.as_number()?.as_isize()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<usize> for &'s ObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<usize, Self::Error> {
-        let value = self.as_number()?.as_usize();
+        let value = self.as_number()?.as_usize()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<f32> for &'s ObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<f32, Self::Error> {
-        let value = self.as_double()?.as_f32();
+        let value = self.as_f32()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<f64> for &'s ObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<f64, Self::Error> {
-        let value = self.as_double()?.as_f64();
+        let value = self.as_f64()?;
         Ok(value)
     }
 }
@@ -1,5 +1,8 @@
+use std::ffi::{CStr, CString};
+
 use crate::{
-    AnyValueAsStr, BoolValue, DoubleValue, MaybeEmptyValue, NumberValue, ObjectValue, StringValue,
+    AnyValueAsStr, BoolValue, ByteStringValue, CStringValue, CharValue, DoubleValue, ErrorKind,
+    MaybeEmptyValue, NumberValue, ObjectValue, StringValue, TypesReaderError,
 };
 
 #[derive(Debug)]
@@ -32,21 +35,36 @@ impl OptionalObjectValue {
         }
     }
 
-    pub fn unwrap_value(&self) -> Result<&ObjectValue, syn::Error> {
+    pub fn unwrap_value(&self) -> Result<&ObjectValue, TypesReaderError> {
         match self {
-            Self::Empty(itm) => Err(syn::Error::new_spanned(
-                itm,
-                "Expecting value but found no value",
-            )),
-            Self::None(itm) => Err(syn::Error::new_spanned(
-                itm,
-                "Expecting value but found no value",
-            )),
+            Self::Empty(_) | Self::None(_) => {
+                Err(self.no_value_error("value", "Expecting value but found no value"))
+            }
             Self::SingleValue(value) => Ok(value),
             Self::Value { value, .. } => Ok(value),
         }
     }
 
+    /// Name of the value type as it is used in the error messages. `no value` if there is no value
+    pub fn get_type_name(&self) -> &'static str {
+        match self {
+            Self::Empty(_) => "no value",
+            Self::None(_) => "no value",
+            Self::SingleValue(value) => value.get_type_name(),
+            Self::Value { value, .. } => value.get_type_name(),
+        }
+    }
+
+    fn no_value_error(&self, expected: &str, message: &str) -> TypesReaderError {
+        TypesReaderError::new(
+            ErrorKind::TypeMismatch {
+                expected: expected.to_string(),
+                found: "no value".to_string(),
+            },
+            self.throw_error(message),
+        )
+    }
+
     pub fn has_no_value(&self) -> bool {
         match self {
             Self::Empty(_) => true,
@@ -80,19 +98,18 @@ impl OptionalObjectValue {
         }
     }
 
-    pub fn as_string(&self) -> Result<&StringValue, syn::Error> {
+    pub fn as_string(&self) -> Result<&StringValue, TypesReaderError> {
         match self {
-            Self::Empty(id) => Err(syn::Error::new_spanned(id, "Expecting String value")),
-            Self::None(ident) => Err(syn::Error::new_spanned(
-                ident,
-                "Expecting String value but found no value",
-            )),
+            Self::Empty(_) => Err(self.no_value_error("string", "Expecting String value")),
+            Self::None(_) => {
+                Err(self.no_value_error("string", "Expecting String value but found no value"))
+            }
             Self::SingleValue(value) => value.as_string(),
             Self::Value { value, .. } => value.as_string(),
         }
     }
 
-    pub fn try_as_string(&self) -> Result<Option<&StringValue>, syn::Error> {
+    pub fn try_as_string(&self) -> Result<Option<&StringValue>, TypesReaderError> {
         match self {
             Self::Empty(_) => Ok(None),
             Self::None(_) => Ok(None),
@@ -101,19 +118,81 @@ impl OptionalObjectValue {
         }
     }
 
-    pub fn as_number(&self) -> Result<&NumberValue, syn::Error> {
+    pub fn as_byte_string(&self) -> Result<&ByteStringValue, TypesReaderError> {
         match self {
-            Self::Empty(id) => Err(syn::Error::new_spanned(id, "Expecting Number value")),
-            Self::None(ident) => Err(syn::Error::new_spanned(
-                ident,
-                "Expecting Number value but found no value",
+            Self::Empty(_) => {
+                Err(self.no_value_error("byte string", "Expecting Byte String value"))
+            }
+            Self::None(_) => Err(self.no_value_error(
+                "byte string",
+                "Expecting Byte String value but found no value",
             )),
+            Self::SingleValue(value) => value.as_byte_string(),
+            Self::Value { value, .. } => value.as_byte_string(),
+        }
+    }
+
+    pub fn try_as_byte_string(&self) -> Result<Option<&ByteStringValue>, TypesReaderError> {
+        match self {
+            Self::Empty(_) => Ok(None),
+            Self::None(_) => Ok(None),
+            Self::SingleValue(value) => Ok(Some(value.as_byte_string()?)),
+            Self::Value { value, .. } => Ok(Some(value.as_byte_string()?)),
+        }
+    }
+
+    pub fn as_char(&self) -> Result<&CharValue, TypesReaderError> {
+        match self {
+            Self::Empty(_) => Err(self.no_value_error("char", "Expecting Char value")),
+            Self::None(_) => {
+                Err(self.no_value_error("char", "Expecting Char value but found no value"))
+            }
+            Self::SingleValue(value) => value.as_char(),
+            Self::Value { value, .. } => value.as_char(),
+        }
+    }
+
+    pub fn try_as_char(&self) -> Result<Option<&CharValue>, TypesReaderError> {
+        match self {
+            Self::Empty(_) => Ok(None),
+            Self::None(_) => Ok(None),
+            Self::SingleValue(value) => Ok(Some(value.as_char()?)),
+            Self::Value { value, .. } => Ok(Some(value.as_char()?)),
+        }
+    }
+
+    pub fn as_c_string(&self) -> Result<&CStringValue, TypesReaderError> {
+        match self {
+            Self::Empty(_) => Err(self.no_value_error("C string", "Expecting C String value")),
+            Self::None(_) => {
+                Err(self.no_value_error("C string", "Expecting C String value but found no value"))
+            }
+            Self::SingleValue(value) => value.as_c_string(),
+            Self::Value { value, .. } => value.as_c_string(),
+        }
+    }
+
+    pub fn try_as_c_string(&self) -> Result<Option<&CStringValue>, TypesReaderError> {
+        match self {
+            Self::Empty(_) => Ok(None),
+            Self::None(_) => Ok(None),
+            Self::SingleValue(value) => Ok(Some(value.as_c_string()?)),
+            Self::Value { value, .. } => Ok(Some(value.as_c_string()?)),
+        }
+    }
+
+    pub fn as_number(&self) -> Result<&NumberValue, TypesReaderError> {
+        match self {
+            Self::Empty(_) => Err(self.no_value_error("number", "Expecting Number value")),
+            Self::None(_) => {
+                Err(self.no_value_error("number", "Expecting Number value but found no value"))
+            }
             Self::SingleValue(value) => value.as_number(),
             Self::Value { value, .. } => value.as_number(),
         }
     }
 
-    pub fn try_as_number(&self) -> Result<Option<&NumberValue>, syn::Error> {
+    pub fn try_as_number(&self) -> Result<Option<&NumberValue>, TypesReaderError> {
         match self {
             Self::Empty(_) => Ok(None),
             Self::None(_) => Ok(None),
@@ -122,19 +201,18 @@ impl OptionalObjectValue {
         }
     }
 
-    pub fn as_double(&self) -> Result<&DoubleValue, syn::Error> {
+    pub fn as_double(&self) -> Result<&DoubleValue, TypesReaderError> {
         match self {
-            Self::Empty(id) => Err(syn::Error::new_spanned(id, "Expecting Float value")),
-            Self::None(ident) => Err(syn::Error::new_spanned(
-                ident,
-                "Expecting Float value but found no value",
-            )),
+            Self::Empty(_) => Err(self.no_value_error("float", "Expecting Float value")),
+            Self::None(_) => {
+                Err(self.no_value_error("float", "Expecting Float value but found no value"))
+            }
             Self::SingleValue(value) => value.as_double(),
             Self::Value { value, .. } => value.as_double(),
         }
     }
 
-    pub fn try_as_double(&self) -> Result<Option<&DoubleValue>, syn::Error> {
+    pub fn try_as_double(&self) -> Result<Option<&DoubleValue>, TypesReaderError> {
         match self {
             Self::Empty(_) => Ok(None),
             Self::None(_) => Ok(None),
@@ -143,23 +221,22 @@ impl OptionalObjectValue {
         }
     }
 
-    pub fn unwrap_any_value_as_str(&self) -> Result<&dyn AnyValueAsStr, syn::Error> {
+    pub fn unwrap_any_value_as_str(&self) -> Result<&dyn AnyValueAsStr, TypesReaderError> {
         Ok(self)
     }
 
-    pub fn as_bool(&self) -> Result<&BoolValue, syn::Error> {
+    pub fn as_bool(&self) -> Result<&BoolValue, TypesReaderError> {
         match self {
-            Self::Empty(id) => Err(syn::Error::new_spanned(id, "Expecting Bool value")),
-            Self::None(ident) => Err(syn::Error::new_spanned(
-                ident,
-                "Expecting Bool value but found no value",
-            )),
+            Self::Empty(_) => Err(self.no_value_error("bool", "Expecting Bool value")),
+            Self::None(_) => {
+                Err(self.no_value_error("bool", "Expecting Bool value but found no value"))
+            }
             Self::SingleValue(value) => value.as_bool(),
             Self::Value { value, .. } => value.as_bool(),
         }
     }
 
-    pub fn try_as_bool(&self) -> Result<Option<&BoolValue>, syn::Error> {
+    pub fn try_as_bool(&self) -> Result<Option<&BoolValue>, TypesReaderError> {
         match self {
             Self::Empty(_) => Ok(None),
             Self::None(_) => Ok(None),
@@ -198,15 +275,16 @@ impl<'s> AnyValueAsStr<'s> for OptionalObjectValue {
 }
 
 impl<'s> TryInto<&'s ObjectValue> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<&'s ObjectValue, Self::Error> {
-        self.unwrap_value()
+        let value = self.unwrap_value()?;
+        Ok(value)
     }
 }
 
 impl<'s> TryInto<Option<&'s ObjectValue>> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<Option<&'s ObjectValue>, Self::Error> {
         Ok(self.try_unwrap_value())
@@ -214,7 +292,7 @@ impl<'s> TryInto<Option<&'s ObjectValue>> for &'s OptionalObjectValue {
 }
 
 impl<'s> TryInto<&'s str> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<&'s str, Self::Error> {
         let value = self.as_string()?.as_str();
@@ -223,7 +301,7 @@ impl<'s> TryInto<&'s str> for &'s OptionalObjectValue {
 }
 
 impl<'s> TryInto<Option<&'s str>> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<Option<&'s str>, Self::Error> {
         match self.try_as_string()? {
@@ -234,7 +312,7 @@ impl<'s> TryInto<Option<&'s str>> for &'s OptionalObjectValue {
 }
 
 impl<'s> TryInto<String> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<String, Self::Error> {
         let value = self.as_string()?.as_str();
@@ -243,7 +321,7 @@ impl<'s> TryInto<String> for &'s OptionalObjectValue {
 }
 
 impl<'s> TryInto<Option<String>> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<Option<String>, Self::Error> {
         match self.try_as_string()? {
@@ -253,204 +331,381 @@ impl<'s> TryInto<Option<String>> for &'s OptionalObjectValue {
     }
 }
 
+impl<'s> TryInto<&'s syn::Path> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<&'s syn::Path, Self::Error> {
+        self.unwrap_value()?.try_into()
+    }
+}
+
+impl<'s> TryInto<syn::Path> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<syn::Path, Self::Error> {
+        self.unwrap_value()?.try_into()
+    }
+}
+
+impl<'s> TryInto<syn::Ident> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<syn::Ident, Self::Error> {
+        self.unwrap_value()?.try_into()
+    }
+}
+
+impl<'s> TryInto<syn::Type> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<syn::Type, Self::Error> {
+        Ok(self.unwrap_value()?.parse_as()?)
+    }
+}
+
+impl<'s> TryInto<syn::Expr> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<syn::Expr, Self::Error> {
+        Ok(self.unwrap_value()?.parse_as()?)
+    }
+}
+
+impl<'s> TryInto<syn::ExprClosure> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<syn::ExprClosure, Self::Error> {
+        Ok(self.unwrap_value()?.parse_as()?)
+    }
+}
+
+impl<'s> TryInto<char> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<char, Self::Error> {
+        let value = self.as_char()?.get_value();
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<Option<char>> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<Option<char>, Self::Error> {
+        match self.try_as_char()? {
+            Some(value) => Ok(Some(value.get_value())),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'s> TryInto<&'s [u8]> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<&'s [u8], Self::Error> {
+        let value = self.as_byte_string()?.as_bytes();
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<Option<&'s [u8]>> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<Option<&'s [u8]>, Self::Error> {
+        match self.try_as_byte_string()? {
+            Some(value) => Ok(Some(value.as_bytes())),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'s> TryInto<Vec<u8>> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<Vec<u8>, Self::Error> {
+        let value = self.as_byte_string()?.to_vec();
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<Option<Vec<u8>>> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<Option<Vec<u8>>, Self::Error> {
+        match self.try_as_byte_string()? {
+            Some(value) => Ok(Some(value.to_vec())),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'s> TryInto<&'s CStr> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<&'s CStr, Self::Error> {
+        let value = self.as_c_string()?.as_c_str();
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<CString> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<CString, Self::Error> {
+        let value = self.as_c_string()?.to_c_string();
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<Option<CString>> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<Option<CString>, Self::Error> {
+        match self.try_as_c_string()? {
+            Some(value) => Ok(Some(value.to_c_string())),
+            None => Ok(None),
+        }
+    }
+}
+
 impl<'s> TryInto<i8> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<i8, Self::Error> {
-        let value = self.as_number()?.as_i8();
+        let value = self.as_number()?.as_i8()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<Option<i8>> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<Option<i8>, Self::Error> {
         match self.try_as_number()? {
-            Some(value) => Ok(Some(value.as_i8())),
+            Some(value) => Ok(Some(value.as_i8()?)),
             None => Ok(None),
         }
     }
 }
 
 impl<'s> TryInto<u8> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<u8, Self::Error> {
-        let value = self.as_number()?.as_u8();
+        let value = self.as_number()?.as_u8()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<Option<u8>> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<Option<u8>, Self::Error> {
         match self.try_as_number()? {
-            Some(value) => Ok(Some(value.as_u8())),
+            Some(value) => Ok(Some(value.as_u8()?)),
             None => Ok(None),
         }
     }
 }
 
 impl<'s> TryInto<i16> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<i16, Self::Error> {
-        let value = self.as_number()?.as_i16();
+        let value = self.as_number()?.as_i16()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<Option<i16>> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<Option<i16>, Self::Error> {
         match self.try_as_number()? {
-            Some(value) => Ok(Some(value.as_i16())),
+            Some(value) => Ok(Some(value.as_i16()?)),
             None => Ok(None),
         }
     }
 }
 
 impl<'s> TryInto<u16> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<u16, Self::Error> {
-        let value = self.as_number()?.as_u16();
+        let value = self.as_number()?.as_u16()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<Option<u16>> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<Option<u16>, Self::Error> {
         match self.try_as_number()? {
-            Some(value) => Ok(Some(value.as_u16())),
+            Some(value) => Ok(Some(value.as_u16()?)),
             None => Ok(None),
         }
     }
 }
 
 impl<'s> TryInto<i32> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<i32, Self::Error> {
-        let value = self.as_number()?.as_i32();
+        let value = self.as_number()?.as_i32()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<Option<i32>> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<Option<i32>, Self::Error> {
         match self.try_as_number()? {
-            Some(value) => Ok(Some(value.as_i32())),
+            Some(value) => Ok(Some(value.as_i32()?)),
             None => Ok(None),
         }
     }
 }
 
 impl<'s> TryInto<u32> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<u32, Self::Error> {
-        let value = self.as_number()?.as_u32();
+        let value = self.as_number()?.as_u32()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<Option<u32>> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<Option<u32>, Self::Error> {
         match self.try_as_number()? {
-            Some(value) => Ok(Some(value.as_u32())),
+            Some(value) => Ok(Some(value.as_u32()?)),
             None => Ok(None),
         }
     }
 }
 
 impl<'s> TryInto<i64> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<i64, Self::Error> {
-        let value = self.as_number()?.as_i64();
+        let value = self.as_number()?.as_i64()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<Option<i64>> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<Option<i64>, Self::Error> {
         match self.try_as_number()? {
-            Some(value) => Ok(Some(value.as_i64())),
+            Some(value) => Ok(Some(value.as_i64()?)),
             None => Ok(None),
         }
     }
 }
 
 impl<'s> TryInto<u64> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<u64, Self::Error> {
-        let value = self.as_number()?.as_u64();
+        let value = self.as_number()?.as_u64()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<Option<u64>> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<Option<u64>, Self::Error> {
         match self.try_as_number()? {
-            Some(value) => Ok(Some(value.as_u64())),
+            Some(value) => Ok(Some(value.as_u64()?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'s> TryInto<u128> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<u128, Self::Error> {
+        let value = self.as_number()?.as_u128()?;
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<Option<u128>> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<Option<u128>, Self::Error> {
+        match self.try_as_number()? {
+            Some(value) => Ok(Some(value.as_u128()?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'s> TryInto<i128> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<i128, Self::Error> {
+        let value = self.as_number()?.as_i128()?;
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<Option<i128>> for &'s OptionalObjectValue {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<Option<i128>, Self::Error> {
+        match self.try_as_number()? {
+            Some(value) => Ok(Some(value.as_i128()?)),
             None => Ok(None),
         }
     }
 }
 
 impl<'s> TryInto<isize> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<isize, Self::Error> {
-        let value = self.as_number()?.as_isize();
+        let value = self.as_number()?.as_isize()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<usize> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<usize, Self::Error> {
-        let value = self.as_number()?.as_usize();
+        let value = self.as_number()?.as_usize()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<f32> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<f32, Self::Error> {
-        let value = self.as_double()?.as_f32();
+        let value = self.unwrap_value()?.as_f32()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<f64> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<f64, Self::Error> {
-        let value = self.as_double()?.as_f64();
+        let value = self.unwrap_value()?.as_f64()?;
         Ok(value)
     }
 }
 
 impl<'s> TryInto<bool> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<bool, Self::Error> {
         let value = self.as_bool()?.get_value();
@@ -459,7 +714,7 @@ impl<'s> TryInto<bool> for &'s OptionalObjectValue {
 }
 
 impl<'s> TryInto<Option<bool>> for &'s OptionalObjectValue {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<Option<bool>, Self::Error> {
         match self.try_as_bool()? {
@@ -471,9 +726,9 @@ impl<'s> TryInto<Option<bool>> for &'s OptionalObjectValue {
 
 impl<'s, T: Clone> TryInto<MaybeEmptyValue<T>> for &'s OptionalObjectValue
 where
-    T: TryFrom<&'s ObjectValue, Error = syn::Error>,
+    T: TryFrom<&'s ObjectValue, Error = TypesReaderError>,
 {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<MaybeEmptyValue<T>, Self::Error> {
         match self {
@@ -0,0 +1,76 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+
+use crate::TokensReader;
+
+/// Path written as a value. E.g.: `handler: crate::api::get_user` or `ty: std::string::String`
+#[derive(Debug)]
+pub struct PathValue {
+    path: syn::Path,
+    value: String,
+}
+
+impl PathValue {
+    pub fn new(path: syn::Path) -> Self {
+        let mut value = String::new();
+
+        if path.leading_colon.is_some() {
+            value.push_str("::");
+        }
+
+        for (index, segment) in path.segments.iter().enumerate() {
+            if index > 0 {
+                value.push_str("::");
+            }
+            value.push_str(segment.ident.to_string().as_str());
+        }
+
+        Self { path, value }
+    }
+
+    /// Reads the rest of the path when the first segment is already read.
+    /// `first_segment` holds the tokens read so far: `ident` or `::ident`
+    pub fn read(
+        mut first_segment: TokenStream,
+        token_reader: &mut TokensReader,
+    ) -> Result<Self, syn::Error> {
+        while let Some(separator) = token_reader.try_read_path_separator() {
+            first_segment.extend(separator);
+
+            let ident = match token_reader.read_next_token()?.try_unwrap_into_ident() {
+                Ok(ident) => ident,
+                Err(next_token) => {
+                    return Err(next_token.throw_error("Expected path segment after '::'"));
+                }
+            };
+
+            first_segment.extend(ident.into_token_stream());
+        }
+
+        let path: syn::Path = syn::parse2(first_segment)?;
+        Ok(Self::new(path))
+    }
+
+    pub fn get_path(&self) -> &syn::Path {
+        &self.path
+    }
+
+    pub fn get_last_ident(&self) -> &syn::Ident {
+        &self.path.segments.last().unwrap().ident
+    }
+
+    /// The path as it is written without spaces. E.g.: `crate::api::get_user`
+    pub fn as_str(&self) -> &str {
+        self.value.as_str()
+    }
+
+    pub fn throw_error(&self, message: &str) -> syn::Error {
+        syn::Error::new_spanned(&self.path, message)
+    }
+}
+
+impl ToTokens for PathValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.path.to_tokens(tokens)
+    }
+}
@@ -0,0 +1,55 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+
+/// Value which is not a literal, an ident or a path, but a piece of Rust syntax.
+/// E.g.: `ty: Option<Vec<u8>>`, `default: Vec::new()` or `check: |v| v.len() < 10`.
+/// Tokens are kept as they are with their spans and parsed on demand.
+#[derive(Debug)]
+pub struct RawTokensValue {
+    token_stream: TokenStream,
+    value: String,
+}
+
+impl RawTokensValue {
+    pub fn new(token_stream: TokenStream) -> Self {
+        let value = token_stream.to_string();
+        Self {
+            token_stream,
+            value,
+        }
+    }
+
+    pub fn get_token_stream(&self) -> &TokenStream {
+        &self.token_stream
+    }
+
+    pub fn parse_as<T: syn::parse::Parse>(&self) -> Result<T, syn::Error> {
+        syn::parse2(self.token_stream.clone())
+    }
+
+    pub fn as_type(&self) -> Result<syn::Type, syn::Error> {
+        self.parse_as()
+    }
+
+    pub fn as_expr(&self) -> Result<syn::Expr, syn::Error> {
+        self.parse_as()
+    }
+
+    pub fn as_closure(&self) -> Result<syn::ExprClosure, syn::Error> {
+        self.parse_as()
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.value.as_str()
+    }
+
+    pub fn throw_error(&self, message: &str) -> syn::Error {
+        syn::Error::new_spanned(&self.token_stream, message)
+    }
+}
+
+impl ToTokens for RawTokensValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.token_stream.to_tokens(tokens)
+    }
+}
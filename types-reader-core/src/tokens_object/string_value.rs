@@ -8,17 +8,29 @@ use crate::TokenValue;
 pub struct StringValue {
     literal: TokenValue,
     value: String,
+    source: String,
 }
 
 impl StringValue {
     pub fn new(literal: TokenValue, value: String) -> Self {
-        Self { literal, value }
+        let source = literal.to_string();
+        Self {
+            literal,
+            value,
+            source,
+        }
     }
 
+    /// The decoded string. E.g.: `\n` is a new line
     pub fn as_str(&self) -> &str {
         self.value.as_str()
     }
 
+    /// Source code of the literal. E.g.: `"a\nb"` or `r#"text"#`
+    pub fn as_source(&self) -> &str {
+        self.source.as_str()
+    }
+
     pub fn to_string(&self) -> String {
         self.value.clone()
     }
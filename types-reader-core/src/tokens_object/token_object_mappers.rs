@@ -1,7 +1,13 @@
-use crate::TokensObject;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    ffi::{CStr, CString},
+    hash::Hash,
+};
+
+use crate::{ErrorKind, ErrorsAccumulator, OrderedMap, TokensObject, TypesReaderError};
 
 impl<'s> TryInto<&'s str> for &'s TokensObject {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<&'s str, Self::Error> {
         let value = self.unwrap_as_value()?;
@@ -11,7 +17,7 @@ impl<'s> TryInto<&'s str> for &'s TokensObject {
 }
 
 impl<'s> TryInto<String> for &'s TokensObject {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<String, Self::Error> {
         let value = self.unwrap_as_value()?;
@@ -21,7 +27,7 @@ impl<'s> TryInto<String> for &'s TokensObject {
 }
 
 impl<'s> TryInto<bool> for &'s TokensObject {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<bool, Self::Error> {
         let value = self.unwrap_as_value()?;
@@ -30,122 +36,452 @@ impl<'s> TryInto<bool> for &'s TokensObject {
     }
 }
 
+impl<'s> TryInto<&'s syn::Path> for &'s TokensObject {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<&'s syn::Path, Self::Error> {
+        let value = self.unwrap_as_value()?;
+        value.try_into()
+    }
+}
+
+impl<'s> TryInto<syn::Path> for &'s TokensObject {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<syn::Path, Self::Error> {
+        let value = self.unwrap_as_value()?;
+        value.try_into()
+    }
+}
+
+impl<'s> TryInto<syn::Ident> for &'s TokensObject {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<syn::Ident, Self::Error> {
+        let value = self.unwrap_as_value()?;
+        value.try_into()
+    }
+}
+
+impl<'s> TryInto<syn::Type> for &'s TokensObject {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<syn::Type, Self::Error> {
+        Ok(self.unwrap_as_value()?.unwrap_value()?.parse_as()?)
+    }
+}
+
+impl<'s> TryInto<syn::Expr> for &'s TokensObject {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<syn::Expr, Self::Error> {
+        Ok(self.unwrap_as_value()?.unwrap_value()?.parse_as()?)
+    }
+}
+
+impl<'s> TryInto<syn::ExprClosure> for &'s TokensObject {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<syn::ExprClosure, Self::Error> {
+        Ok(self.unwrap_as_value()?.unwrap_value()?.parse_as()?)
+    }
+}
+
+impl<'s> TryInto<char> for &'s TokensObject {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<char, Self::Error> {
+        let value = self.unwrap_as_value()?;
+        let value = value.as_char()?.get_value();
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<&'s [u8]> for &'s TokensObject {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<&'s [u8], Self::Error> {
+        let value = self.unwrap_as_value()?;
+        let value = value.as_byte_string()?.as_bytes();
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<Vec<u8>> for &'s TokensObject {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<Vec<u8>, Self::Error> {
+        if let Some(items) = self.try_get_vec() {
+            let mut result = Vec::with_capacity(items.len());
+            for item in items {
+                result.push(item.try_into()?);
+            }
+            return Ok(result);
+        }
+
+        let value = self.unwrap_as_value()?;
+        let value = value.as_byte_string()?.to_vec();
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<&'s CStr> for &'s TokensObject {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<&'s CStr, Self::Error> {
+        let value = self.unwrap_as_value()?;
+        let value = value.as_c_string()?.as_c_str();
+        Ok(value)
+    }
+}
+
+impl<'s> TryInto<CString> for &'s TokensObject {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<CString, Self::Error> {
+        let value = self.unwrap_as_value()?;
+        let value = value.as_c_string()?.to_c_string();
+        Ok(value)
+    }
+}
+
 impl<'s> TryInto<i8> for &'s TokensObject {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<i8, Self::Error> {
         let value = self.unwrap_as_value()?;
-        let result = value.as_number()?.as_i8();
+        let result = value.as_number()?.as_i8()?;
         Ok(result)
     }
 }
 
 impl<'s> TryInto<u8> for &'s TokensObject {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<u8, Self::Error> {
         let value = self.unwrap_as_value()?;
-        let result = value.as_number()?.as_u8();
+        let result = value.as_number()?.as_u8()?;
         Ok(result)
     }
 }
 
 impl<'s> TryInto<i16> for &'s TokensObject {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<i16, Self::Error> {
         let value = self.unwrap_as_value()?;
-        let result = value.as_number()?.as_i16();
+        let result = value.as_number()?.as_i16()?;
         Ok(result)
     }
 }
 
 impl<'s> TryInto<u16> for &'s TokensObject {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<u16, Self::Error> {
         let value = self.unwrap_as_value()?;
-        let result = value.as_number()?.as_u16();
+        let result = value.as_number()?.as_u16()?;
         Ok(result)
     }
 }
 
 impl<'s> TryInto<i32> for &'s TokensObject {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<i32, Self::Error> {
         let value = self.unwrap_as_value()?;
-        let result = value.as_number()?.as_i32();
+        let result = value.as_number()?.as_i32()?;
         Ok(result)
     }
 }
 
 impl<'s> TryInto<u32> for &'s TokensObject {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<u32, Self::Error> {
         let value = self.unwrap_as_value()?;
-        let result = value.as_number()?.as_u32();
+        let result = value.as_number()?.as_u32()?;
         Ok(result)
     }
 }
 
 impl<'s> TryInto<i64> for &'s TokensObject {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<i64, Self::Error> {
         let value = self.unwrap_as_value()?;
-        let result = value.as_number()?.as_i64();
+        let result = value.as_number()?.as_i64()?;
         Ok(result)
     }
 }
 
 impl<'s> TryInto<u64> for &'s TokensObject {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<u64, Self::Error> {
         let value = self.unwrap_as_value()?;
-        let result = value.as_number()?.as_u64();
+        let result = value.as_number()?.as_u64()?;
+        Ok(result)
+    }
+}
+
+impl<'s> TryInto<u128> for &'s TokensObject {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<u128, Self::Error> {
+        let value = self.unwrap_as_value()?;
+        let result = value.as_number()?.as_u128()?;
+        Ok(result)
+    }
+}
+
+impl<'s> TryInto<i128> for &'s TokensObject {
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<i128, Self::Error> {
+        let value = self.unwrap_as_value()?;
+        let result = value.as_number()?.as_i128()?;
         Ok(result)
     }
 }
 
 impl<'s> TryInto<isize> for &'s TokensObject {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<isize, Self::Error> {
         let value = self.unwrap_as_value()?;
-        let result = value.as_number()?.as_isize();
+        let result = value.as_number()?.as_isize()?;
         Ok(result)
     }
 }
 
 impl<'s> TryInto<usize> for &'s TokensObject {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<usize, Self::Error> {
         let value = self.unwrap_as_value()?;
-        let result = value.as_number()?.as_usize();
+        let result = value.as_number()?.as_usize()?;
         Ok(result)
     }
 }
 
 impl<'s> TryInto<f32> for &'s TokensObject {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<f32, Self::Error> {
         let value = self.unwrap_as_value()?;
-        let result = value.as_double()?.as_f32();
+        let result = value.unwrap_value()?.as_f32()?;
         Ok(result)
     }
 }
 
 impl<'s> TryInto<f64> for &'s TokensObject {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<f64, Self::Error> {
         let value = self.unwrap_as_value()?;
-        let result = value.as_double()?.as_f64();
+        let result = value.unwrap_value()?.as_f64()?;
         Ok(result)
     }
 }
+
+/// Key of the map read from the object. E.g.: `{ "x-id": "1", accept: "json" }`
+pub trait MapKey<'s> {
+    fn from_param_name(name: &'s str) -> Self;
+}
+
+impl<'s> MapKey<'s> for &'s str {
+    fn from_param_name(name: &'s str) -> Self {
+        name
+    }
+}
+
+impl<'s> MapKey<'s> for String {
+    fn from_param_name(name: &'s str) -> Self {
+        name.to_string()
+    }
+}
+
+/// Every value of the map is read the way the field of its type is.
+/// The errors of the values are reported at once with the key in the path
+fn read_map<'s, K: MapKey<'s>, V, TMap: FromIterator<(K, V)>>(
+    tokens_object: &'s TokensObject,
+) -> Result<TMap, TypesReaderError>
+where
+    &'s TokensObject: TryInto<V, Error = TypesReaderError>,
+{
+    let mut errors = ErrorsAccumulator::new();
+    let mut result = Vec::new();
+
+    for (name, value) in tokens_object.get_map_params()? {
+        if let Some(value) =
+            errors.handle(crate::utils::in_field(name, TryInto::<V>::try_into(value)))
+        {
+            result.push((K::from_param_name(name), value));
+        }
+    }
+
+    errors.check()?;
+
+    Ok(result.into_iter().collect())
+}
+
+impl<'s, K: MapKey<'s> + Eq + Hash, V> TryInto<HashMap<K, V>> for &'s TokensObject
+where
+    &'s TokensObject: TryInto<V, Error = TypesReaderError>,
+{
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<HashMap<K, V>, Self::Error> {
+        read_map::<K, V, HashMap<K, V>>(self)
+    }
+}
+
+impl<'s, K: MapKey<'s> + Ord, V> TryInto<BTreeMap<K, V>> for &'s TokensObject
+where
+    &'s TokensObject: TryInto<V, Error = TypesReaderError>,
+{
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<BTreeMap<K, V>, Self::Error> {
+        read_map::<K, V, BTreeMap<K, V>>(self)
+    }
+}
+
+impl<'s, K: MapKey<'s> + Eq + Hash + Clone, V> TryInto<OrderedMap<K, V>> for &'s TokensObject
+where
+    &'s TokensObject: TryInto<V, Error = TypesReaderError>,
+{
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<OrderedMap<K, V>, Self::Error> {
+        read_map::<K, V, OrderedMap<K, V>>(self)
+    }
+}
+
+/// Every item is read the way the field of its type is.
+/// The errors of the items are reported at once with the index in the path
+fn read_items<'s, T>(items: &'s [TokensObject]) -> Result<Vec<T>, TypesReaderError>
+where
+    &'s TokensObject: TryInto<T, Error = TypesReaderError>,
+{
+    let mut errors = ErrorsAccumulator::new();
+    let mut result = Vec::with_capacity(items.len());
+
+    for (index, item) in items.iter().enumerate() {
+        if let Some(value) =
+            errors.handle(crate::utils::in_item(index, TryInto::<T>::try_into(item)))
+        {
+            result.push(value);
+        }
+    }
+
+    errors.check()?;
+
+    Ok(result)
+}
+
+impl<'s, T, const N: usize> TryInto<[T; N]> for &'s TokensObject
+where
+    &'s TokensObject: TryInto<T, Error = TypesReaderError>,
+{
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<[T; N], Self::Error> {
+        let items = self.get_sequence_items()?;
+        self.check_items_len(items, N)?;
+
+        match read_items::<T>(items)?.try_into() {
+            Ok(result) => Ok(result),
+            Err(_) => unreachable!("The amount of items is checked"),
+        }
+    }
+}
+
+/// The sets reject the repeated items, since they are most likely a typo
+fn read_set<'s, T, TSet: Default>(
+    items: &'s [TokensObject],
+    insert: impl Fn(&mut TSet, T) -> bool,
+) -> Result<TSet, TypesReaderError>
+where
+    &'s TokensObject: TryInto<T, Error = TypesReaderError>,
+{
+    let mut errors = ErrorsAccumulator::new();
+    let mut result = TSet::default();
+
+    for (index, (item, value)) in items.iter().zip(read_items::<T>(items)?).enumerate() {
+        if !insert(&mut result, value) {
+            let err = TypesReaderError::new(
+                ErrorKind::InvalidValue,
+                item.throw_error_at_value_token("Item is already in the set"),
+            );
+            errors.push(err.with_context(format!("[{}]", index).as_str()));
+        }
+    }
+
+    errors.into_result(result)
+}
+
+impl<'s, T: Eq + Hash> TryInto<HashSet<T>> for &'s TokensObject
+where
+    &'s TokensObject: TryInto<T, Error = TypesReaderError>,
+{
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<HashSet<T>, Self::Error> {
+        let items = self.get_sequence_items()?;
+        read_set::<T, HashSet<T>>(items, |set, value| set.insert(value))
+    }
+}
+
+impl<'s, T: Ord> TryInto<BTreeSet<T>> for &'s TokensObject
+where
+    &'s TokensObject: TryInto<T, Error = TypesReaderError>,
+{
+    type Error = TypesReaderError;
+
+    fn try_into(self) -> Result<BTreeSet<T>, Self::Error> {
+        let items = self.get_sequence_items()?;
+        read_set::<T, BTreeSet<T>>(items, |set, value| set.insert(value))
+    }
+}
+
+/// `(T1, T2, ...)` is read from `[1, 2]` or `(1, 2)`
+macro_rules! impl_try_into_tuple {
+    ($len:literal; $($item:ident),+) => {
+        impl<'s, $($item),+> TryInto<($($item,)+)> for &'s TokensObject
+        where
+            $(&'s TokensObject: TryInto<$item, Error = TypesReaderError>,)+
+        {
+            type Error = TypesReaderError;
+
+            #[allow(non_snake_case)]
+            fn try_into(self) -> Result<($($item,)+), Self::Error> {
+                let items = self.get_sequence_items()?;
+                self.check_items_len(items, $len)?;
+
+                let mut errors = ErrorsAccumulator::new();
+                let mut items = items.iter().enumerate();
+
+                $(
+                    let (index, item) = items.next().unwrap();
+                    let $item = errors.handle(crate::utils::in_item(
+                        index,
+                        TryInto::<$item>::try_into(item),
+                    ));
+                )+
+
+                errors.check()?;
+
+                Ok(($($item.unwrap(),)+))
+            }
+        }
+    };
+}
+
+impl_try_into_tuple!(1; T1);
+impl_try_into_tuple!(2; T1, T2);
+impl_try_into_tuple!(3; T1, T2, T3);
+impl_try_into_tuple!(4; T1, T2, T3, T4);
+impl_try_into_tuple!(5; T1, T2, T3, T4, T5);
+impl_try_into_tuple!(6; T1, T2, T3, T4, T5, T6);
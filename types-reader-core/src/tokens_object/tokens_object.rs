@@ -1,14 +1,20 @@
-use crate::{AnyValueAsStr, NextToken, ObjectValue, OptionalObjectValue, TokensReader};
+use crate::{
+    AnyValueAsStr, DuplicateParamPolicy, ErrorKind, ErrorsAccumulator, ObjectItem, ObjectItems,
+    ObjectValue, OptionalObjectValue, PathValue, RawTokensValue, TokensReader, TypesReaderError,
+};
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::ext::IdentExt;
 
-use std::collections::HashMap;
+use std::collections::HashSet;
 #[derive(Debug)]
 pub enum TokensObject {
     Value(OptionalObjectValue),
     Object {
         token_stream: TokenStream,
-        items: HashMap<String, TokensObject>,
+        positional: Vec<TokensObject>,
+        items: ObjectItems,
     },
     Vec {
         token_stream: TokenStream,
@@ -18,93 +24,213 @@ pub enum TokensObject {
 
 const SPACE_SYMBOLS: [char; 2] = [';', ','];
 
+// Punctuation a type or an expression may start with: &str, *const u8, !done, -x, |v| v, <T as Trait>::X, ::std::X, 'a, ..10
+const VALUE_START_SYMBOLS: [char; 9] = ['&', '*', '!', '-', '|', '<', ':', '\'', '.'];
+
 impl TokensObject {
-    pub fn new(mut token_reader: TokensReader) -> Result<Self, syn::Error> {
-        let next_token = token_reader.try_read_next_token()?;
+    pub fn new(token_reader: TokensReader) -> Result<Self, TypesReaderError> {
+        Self::new_with_policy(token_reader, DuplicateParamPolicy::default())
+    }
 
-        if next_token.is_none() {
+    pub fn new_with_policy(
+        mut token_reader: TokensReader,
+        policy: DuplicateParamPolicy,
+    ) -> Result<Self, TypesReaderError> {
+        if token_reader.try_peek_token_tree(0).is_none() {
             return Ok(Self::Value(OptionalObjectValue::Empty(
                 token_reader.into_token_stream(),
             )));
         }
 
-        let next_token = next_token.unwrap();
+        let mut errors = ErrorsAccumulator::new();
 
-        let mut ident_token = match next_token.try_unwrap_as_value() {
-            Ok(token_value) => {
-                let remaining = token_reader.try_read_next_token()?;
-                if let Some(remaining) = remaining {
-                    return Err(remaining.throw_error(
-                        "When using multiple parameters, all parameters must be named. E.g.: name:\"value\", param:true",
-                    ));
-                }
-                return Ok(Self::Value(OptionalObjectValue::SingleValue(
-                    token_value.try_into()?,
-                )));
+        // Positional values go first. E.g.: #[route("/users/{id}", method: GET)]
+        let mut positional = Vec::new();
+
+        while Self::is_single_value(&token_reader) {
+            let value_tokens = Self::read_value_tokens(&mut token_reader);
+
+            if let Some(value) = errors.handle(Self::read_object_value(value_tokens)) {
+                positional.push(Self::Value(OptionalObjectValue::SingleValue(value)));
             }
-            Err(next_token) => next_token,
-        };
 
-        let mut items = HashMap::new();
+            Self::skip_separator(&mut token_reader);
+        }
 
-        loop {
-            let param_name = ident_token.unwrap_into_ident(None)?;
+        if positional.len() == 1 && token_reader.try_peek_token_tree(0).is_none() {
+            return Ok(positional.remove(0));
+        }
 
-            let token_equal = token_reader.try_read_next_token()?;
+        let mut items = ObjectItems::new();
+        let mut duplicates = DuplicatesTracker::new(policy);
 
-            if token_equal.is_none() {
-                let id = param_name.to_string();
-                items.insert(id, Self::Value(OptionalObjectValue::None(param_name)));
-                break;
+        while token_reader.try_peek_token_tree(0).is_some() {
+            let result =
+                Self::read_named_param(&mut token_reader, &mut items, &mut duplicates, policy);
+
+            // Keep reading the parameters after the broken one to report all the errors at once
+            if let Err(err) = result {
+                errors.push(err);
+                Self::skip_to_next_param(&mut token_reader);
+            }
+        }
+
+        errors.into_result(Self::Object {
+            token_stream: token_reader.into_token_stream(),
+            positional,
+            items,
+        })
+    }
+
+    fn read_named_param(
+        token_reader: &mut TokensReader,
+        items: &mut ObjectItems,
+        duplicates: &mut DuplicatesTracker,
+        policy: DuplicateParamPolicy,
+    ) -> Result<(), TypesReaderError> {
+        if Self::is_single_value(token_reader) {
+            let value_tokens = Self::read_value_tokens(token_reader);
+            let value_tokens: TokenStream = value_tokens.into_iter().collect();
+
+            return Err(TypesReaderError::new_spanned(
+                ErrorKind::InvalidSyntax,
+                value_tokens,
+                "Positional parameters must go before named parameters. E.g.: \"value\", name:\"value\"",
+            ));
+        }
+
+        let (name, param_name, key_tokens) = Self::read_param_name(token_reader)?;
+
+        let token_equal = match token_reader.try_read_next_token()? {
+            Some(token_equal) => token_equal,
+            None => {
+                let value = Self::Value(OptionalObjectValue::None(param_name.clone()));
+                return duplicates.insert(items, name, param_name, key_tokens, value);
             }
+        };
 
-            let token_equal = token_equal.unwrap();
+        if token_equal.if_spacing(Some(&SPACE_SYMBOLS)) {
+            let value = Self::Value(OptionalObjectValue::None(param_name.clone()));
+            return duplicates.insert(items, name, param_name, key_tokens, value);
+        }
 
-            if token_equal.if_spacing(Some(&SPACE_SYMBOLS)) {
-                let id = param_name.to_string();
-                items.insert(id, Self::Value(OptionalObjectValue::None(param_name)));
-            } else if token_equal.if_spacing(Some(&[':', '='])) {
-                let token_value = token_reader.read_next_token()?;
-                let id = param_name.to_string();
-                items.insert(id, Self::read_value(param_name, token_value)?);
-            } else {
-                // List form of the parameter. E.g.: name(param: "value")
-                match token_equal.try_unwrap_into_group(None) {
-                    Ok((group_tokens, delimiter)) => {
-                        let id = param_name.to_string();
-                        items.insert(
-                            id,
-                            Self::read_group_value(param_name, group_tokens, delimiter)?,
-                        );
-                    }
-                    Err(token_equal) => {
-                        return Err(token_equal.throw_error(
+        if token_equal.if_spacing(Some(&[':', '='])) {
+            let value = Self::read_value(param_name.clone(), token_reader, policy)?;
+            duplicates.insert(items, name, param_name, key_tokens, value)?;
+        } else {
+            // List form of the parameter. E.g.: name(param: "value")
+            match token_equal.try_unwrap_into_group(None) {
+                Ok((group_tokens, delimiter)) => {
+                    let value = Self::read_group_value(
+                        param_name.clone(),
+                        group_tokens,
+                        delimiter,
+                        policy,
+                    )?;
+                    duplicates.insert(items, name, param_name, key_tokens, value)?;
+                }
+                Err(token_equal) => {
+                    return Err(TypesReaderError::new(
+                        ErrorKind::InvalidSyntax,
+                        token_equal.throw_error(
                             "Expected ':', '=' or a group of parameters. E.g.: name:\"value\", param(value:true)",
-                        ));
-                    }
+                        ),
+                    ));
                 }
             }
+        }
+
+        Self::skip_separator(token_reader);
+
+        Ok(())
+    }
+
+    /// Reads the name of the parameter. `r#type` is named `type`.
+    /// Kebab-case name `content-type` is read as one name; its ident is the first word.
+    /// String literal name `"x-id"` is read as is; its ident is made of the name: `x_id`
+    fn read_param_name(
+        token_reader: &mut TokensReader,
+    ) -> Result<(String, syn::Ident, TokenStream), TypesReaderError> {
+        if let Some(name) = token_reader
+            .try_peek_token_tree(0)
+            .and_then(try_read_string_key)
+        {
+            token_reader.try_get_next_token();
+            let param_name = string_key_to_ident(&name);
+            return Ok((name.value(), param_name, name.to_token_stream()));
+        }
+
+        let name_len = Self::get_param_name_len(token_reader);
 
-            let next_token = token_reader.try_read_next_token()?;
-            if next_token.is_none() {
+        let param_name = token_reader.read_next_token()?.unwrap_into_ident(None)?;
+
+        let mut name = param_name.unraw().to_string();
+        let mut key_tokens = param_name.to_token_stream();
+
+        for _ in 1..name_len {
+            if let Some(token) = token_reader.try_get_next_token() {
+                name.push_str(token.to_string().as_str());
+                key_tokens.extend([token]);
+            }
+        }
+
+        Ok((name, param_name, key_tokens))
+    }
+
+    /// Amount of tokens the name of the parameter takes: `name` - 1, `content-type` - 3.
+    /// Words joined with `-` are a name only if `:`, `=`, a group, a separator or the end goes after them,
+    /// so an expression like `a - b` is not taken for a name
+    fn get_param_name_len(token_reader: &TokensReader) -> usize {
+        let mut len = 1;
+
+        while let (Some(TokenTree::Punct(punct)), Some(TokenTree::Ident(_))) = (
+            token_reader.try_peek_token_tree(len),
+            token_reader.try_peek_token_tree(len + 1),
+        ) {
+            if punct.as_char() != '-' {
                 break;
             }
 
-            ident_token = next_token.unwrap();
+            len += 2;
+        }
+
+        if len == 1 || Self::is_param_name_end(token_reader.try_peek_token_tree(len)) {
+            return len;
+        }
 
-            if ident_token.if_spacing(Some(&SPACE_SYMBOLS)) {
-                let next_token = token_reader.try_read_next_token()?;
-                if next_token.is_none() {
-                    break;
-                }
-                ident_token = next_token.unwrap();
+        1
+    }
+
+    fn is_param_name_end(token: Option<&TokenTree>) -> bool {
+        match token {
+            None => true,
+            Some(TokenTree::Group(_)) => true,
+            Some(TokenTree::Punct(punct)) => match punct.as_char() {
+                ':' => punct.spacing() == Spacing::Alone,
+                '=' | ',' | ';' => true,
+                _ => false,
+            },
+            Some(_) => false,
+        }
+    }
+
+    fn skip_separator(token_reader: &mut TokensReader) {
+        if let Some(TokenTree::Punct(punct)) = token_reader.try_peek_token_tree(0) {
+            if SPACE_SYMBOLS.contains(&punct.as_char()) {
+                token_reader.try_get_next_token();
             }
         }
+    }
 
-        Ok(Self::Object {
-            token_stream: token_reader.into_token_stream(),
-            items,
-        })
+    // Resynchronizes after an error: skips everything up to and including the next ',' or ';'
+    fn skip_to_next_param(token_reader: &mut TokensReader) {
+        while let Some(token) = token_reader.try_get_next_token() {
+            if let TokenTree::Punct(punct) = token {
+                if SPACE_SYMBOLS.contains(&punct.as_char()) {
+                    return;
+                }
+            }
+        }
     }
 
     pub fn create_empty(token_stream: TokenStream) -> Self {
@@ -121,35 +247,235 @@ impl TokensObject {
     pub fn check_for_unknown_params(
         &self,
         used_parameters: &[&'static str],
-    ) -> Result<(), syn::Error> {
+    ) -> Result<(), TypesReaderError> {
         match self {
-            Self::Value(_) => {
-                return Ok(());
-            }
-            Self::Vec { .. } => {
-                return Ok(());
-            }
-            Self::Object {
-                items,
-                token_stream: _,
-            } => {
-                for (param_name, value) in items {
-                    if !used_parameters.iter().any(|itm| *itm == param_name) {
-                        return Err(value.throw_error_at_param_token(
-                            format!(
-                                "Unknown parameter. Parameters are supported: {:?}",
-                                used_parameters
-                            )
-                            .as_str(),
+            Self::Value(_) => Ok(()),
+            Self::Vec { .. } => Ok(()),
+            Self::Object { items, .. } => {
+                let mut errors = ErrorsAccumulator::new();
+                let mut first_unknown_param = None;
+
+                for name in items.names() {
+                    if !used_parameters.contains(&name) {
+                        let param_name = items.get_key_tokens(name).unwrap();
+
+                        errors.push(TypesReaderError::new_spanned(
+                            ErrorKind::UnknownParam {
+                                name: name.to_string(),
+                            },
+                            param_name,
+                            crate::utils::format_unknown_name_message(
+                                "Unknown parameter",
+                                name,
+                                used_parameters,
+                            ),
                         ));
+
+                        first_unknown_param.get_or_insert(param_name);
                     }
                 }
+
+                if let Some(param_name) = first_unknown_param {
+                    errors.push(TypesReaderError::new_note(syn::Error::new_spanned(
+                        param_name,
+                        crate::utils::format_supported_names_note("parameters", used_parameters),
+                    )));
+                }
+
+                errors.check()
+            }
+        }
+    }
+
+    /// Parameters collected with `DuplicateParamPolicy::CollectAsVec` may only be repeated if they are `repeatable`.
+    /// Any other repeated parameter gives the same error as `DuplicateParamPolicy::Error`
+    pub fn check_for_repeated_params(&self, repeatable: &[&str]) -> Result<(), TypesReaderError> {
+        let items = match self {
+            Self::Object { items, .. } => items,
+            _ => return Ok(()),
+        };
+
+        let mut errors = ErrorsAccumulator::new();
+
+        for (name, key_tokens) in items.repeated_keys() {
+            if !repeatable.contains(&name) {
+                let first_key_tokens = items.get_key_tokens(name).unwrap();
+                errors.push(duplicate_param_error(
+                    name.to_string(),
+                    key_tokens,
+                    first_key_tokens,
+                ));
             }
         }
 
+        errors.check()
+    }
+
+    pub fn check_for_unknown_positional_params(
+        &self,
+        positional_amount: usize,
+    ) -> Result<(), TypesReaderError> {
+        let positional = self.get_positional_params();
+
+        if positional.len() > positional_amount {
+            return Err(TypesReaderError::new(
+                ErrorKind::UnknownParam {
+                    name: format!("#{}", positional_amount),
+                },
+                positional[positional_amount].throw_error_at_value_token(
+                    format!(
+                        "Unexpected positional parameter. Amount of positional parameters supported: {}",
+                        positional_amount
+                    )
+                    .as_str(),
+                ),
+            ));
+        }
+
         Ok(())
     }
 
+    /// Named parameters which are not in the list, in the order they are written in
+    pub fn get_unknown_params(&self, used_parameters: &[&str]) -> Vec<(&str, &ObjectItem)> {
+        match self {
+            Self::Object { items, .. } => items
+                .iter()
+                .filter(|(name, _)| !used_parameters.contains(name))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Only one of the parameters can be given. The error points at each given one
+    pub fn check_exclusive_params(&self, names: &[&str]) -> Result<(), TypesReaderError> {
+        let given_names = self.get_given_names(names);
+
+        if given_names.len() < 2 {
+            return Ok(());
+        }
+
+        let message = format!(
+            "Only one of the parameters {} can be given",
+            crate::utils::format_names(names)
+        );
+
+        Err(self.error_at_keys(
+            ErrorKind::ConflictingParams {
+                names: given_names.iter().map(|name| name.to_string()).collect(),
+            },
+            &given_names,
+            &message,
+        ))
+    }
+
+    /// If one of the parameters is given - all of them should be given.
+    /// The error points at each given one
+    pub fn check_params_together(&self, names: &[&str]) -> Result<(), TypesReaderError> {
+        let given_names = self.get_given_names(names);
+
+        if given_names.is_empty() || given_names.len() == names.len() {
+            return Ok(());
+        }
+
+        let missing_names: Vec<&str> = names
+            .iter()
+            .copied()
+            .filter(|name| !given_names.contains(name))
+            .collect();
+
+        let message = format!(
+            "Parameters {} should be given together. Missing: {}",
+            crate::utils::format_names(names),
+            crate::utils::format_names(&missing_names)
+        );
+
+        Err(self.error_at_keys(
+            ErrorKind::MissingParam {
+                name: missing_names[0].to_string(),
+            },
+            &given_names,
+            &message,
+        ))
+    }
+
+    /// At least one of the parameters should be given. The error points at the whole object
+    pub fn check_one_of_required_params(&self, names: &[&str]) -> Result<(), TypesReaderError> {
+        if !self.get_given_names(names).is_empty() {
+            return Ok(());
+        }
+
+        Err(TypesReaderError::new(
+            ErrorKind::MissingParam {
+                name: names.join(" | "),
+            },
+            self.throw_error_at_value_token(
+                format!(
+                    "One of the parameters {} is required",
+                    crate::utils::format_names(names)
+                )
+                .as_str(),
+            ),
+        ))
+    }
+
+    /// If the parameter is given - the required one should be given as well. A flag given as `false` does not count.
+    /// E.g.: `ttl` requires `cache`. The error points at the parameter
+    pub fn check_param_requires(
+        &self,
+        param_name: &str,
+        required_name: &str,
+    ) -> Result<(), TypesReaderError> {
+        if !self.has_param(param_name) {
+            return Ok(());
+        }
+
+        let is_given = match self.try_get_named_param(required_name) {
+            Some(value) => !matches!(
+                value.unwrap_as_value().and_then(|value| value.unwrap_value()),
+                Ok(ObjectValue::Bool(value)) if !value.get_value()
+            ),
+            None => false,
+        };
+
+        if is_given {
+            return Ok(());
+        }
+
+        Err(self.error_at_keys(
+            ErrorKind::MissingParam {
+                name: required_name.to_string(),
+            },
+            &[param_name],
+            format!(
+                "Parameter '{}' requires '{}' to be given",
+                param_name, required_name
+            )
+            .as_str(),
+        ))
+    }
+
+    fn get_given_names<'n>(&self, names: &[&'n str]) -> Vec<&'n str> {
+        names
+            .iter()
+            .copied()
+            .filter(|name| self.has_param(name))
+            .collect()
+    }
+
+    // The same error at each of the given keys
+    fn error_at_keys(&self, kind: ErrorKind, names: &[&str], message: &str) -> TypesReaderError {
+        let mut result = syn::Error::new_spanned(self.get_key_tokens(names[0]).unwrap(), message);
+
+        for name in &names[1..] {
+            result.combine(syn::Error::new_spanned(
+                self.get_key_tokens(name).unwrap(),
+                message,
+            ));
+        }
+
+        TypesReaderError::new(kind, result)
+    }
+
     pub fn throw_error_at_value_token(&self, message: &str) -> syn::Error {
         match self {
             TokensObject::Value(value) => value.throw_error(message),
@@ -174,13 +500,38 @@ impl TokensObject {
         }
     }
 
-    pub fn unwrap_as_vec(&self) -> Result<&Vec<Self>, syn::Error> {
+    pub fn unwrap_as_vec(&self) -> Result<&Vec<Self>, TypesReaderError> {
         match self.try_get_vec() {
             Some(value) => Ok(value),
-            None => Err(self.throw_error_at_param_token("Value should be an object list")),
+            None => Err(self.type_mismatch_error("array", "Value should be an object list")),
+        }
+    }
+
+    pub fn get_type_name(&self) -> &'static str {
+        match self {
+            Self::Value(value) => value.get_type_name(),
+            Self::Object { .. } => "object",
+            Self::Vec { .. } => "array",
         }
     }
 
+    fn type_mismatch_error(&self, expected: &str, message: &str) -> TypesReaderError {
+        TypesReaderError::new(
+            ErrorKind::TypeMismatch {
+                expected: expected.to_string(),
+                found: self.get_type_name().to_string(),
+            },
+            self.throw_error_at_param_token(message),
+        )
+    }
+
+    fn missing_param_error(&self, param_name: String, message: &str) -> TypesReaderError {
+        TypesReaderError::new(
+            ErrorKind::MissingParam { name: param_name },
+            self.throw_error_at_param_token(message),
+        )
+    }
+
     pub fn try_get_vec(&self) -> Option<&Vec<Self>> {
         match self {
             Self::Vec { items, .. } => Some(items),
@@ -188,6 +539,91 @@ impl TokensObject {
         }
     }
 
+    pub fn try_get_object(&self) -> Option<&ObjectItems> {
+        match self {
+            Self::Object { items, .. } => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Entries of the object read as a map: `{ "x-id": "1", accept: "json" }` or `headers(accept: "json")`.
+    /// The empty group is the empty map
+    pub fn get_map_params(&self) -> Result<Vec<(&str, &TokensObject)>, TypesReaderError> {
+        match self {
+            Self::Value(OptionalObjectValue::Empty(_)) => Ok(Vec::new()),
+            Self::Object {
+                positional, items, ..
+            } => {
+                if let Some(value) = positional.first() {
+                    return Err(TypesReaderError::new(
+                        ErrorKind::InvalidSyntax,
+                        value.throw_error_at_value_token(
+                            "Entries of the map should be named. E.g.: { accept: \"json\" }",
+                        ),
+                    ));
+                }
+
+                Ok(items
+                    .iter()
+                    .map(|(name, item)| (name, &item.value))
+                    .collect())
+            }
+            _ => Err(self.type_mismatch_error(
+                "object",
+                "Value should be an object. E.g.: { accept: \"json\" }",
+            )),
+        }
+    }
+
+    /// Items of the array `[1, 2]` or of the tuple `(1, 2)`. A single value in the parentheses is the only item
+    pub fn get_sequence_items(&self) -> Result<&[TokensObject], TypesReaderError> {
+        match self {
+            Self::Vec { items, .. } => Ok(items),
+            Self::Object {
+                positional, items, ..
+            } if items.is_empty() => Ok(positional),
+            Self::Value(OptionalObjectValue::Empty(_)) => Ok(&[]),
+            Self::Value(OptionalObjectValue::SingleValue(_)) => Ok(std::slice::from_ref(self)),
+            _ => Err(self
+                .type_mismatch_error("array", "Value should be an array or a tuple. E.g.: [1, 2]")),
+        }
+    }
+
+    /// The error of the array or the tuple with the wrong amount of items
+    pub fn check_items_len(
+        &self,
+        items: &[TokensObject],
+        expected: usize,
+    ) -> Result<(), TypesReaderError> {
+        if items.len() == expected {
+            return Ok(());
+        }
+
+        Err(TypesReaderError::new(
+            ErrorKind::TypeMismatch {
+                expected: format!("{} items", expected),
+                found: format!("{} items", items.len()),
+            },
+            self.throw_error_at_value_token(
+                format!(
+                    "Value should have {} items. Found {}",
+                    expected,
+                    items.len()
+                )
+                .as_str(),
+            ),
+        ))
+    }
+
+    pub fn get_key_ident(&self, param_name: &str) -> Option<&syn::Ident> {
+        self.try_get_object()?.get_key_ident(param_name)
+    }
+
+    /// Tokens of the whole key of the named parameter: `content-type` is three tokens
+    pub fn get_key_tokens(&self, param_name: &str) -> Option<&TokenStream> {
+        self.try_get_object()?.get_key_tokens(param_name)
+    }
+
     pub fn is_object(&self) -> bool {
         match self {
             Self::Object { .. } => true,
@@ -195,7 +631,7 @@ impl TokensObject {
         }
     }
 
-    pub fn get_named_param(&self, param_name: &str) -> Result<&TokensObject, syn::Error> {
+    pub fn get_named_param(&self, param_name: &str) -> Result<&TokensObject, TypesReaderError> {
         match self {
             Self::Object { items, .. } => match items.get(param_name) {
                 Some(value) => return Ok(value),
@@ -204,8 +640,10 @@ impl TokensObject {
             _ => {}
         }
 
-        Err(self
-            .throw_error_at_param_token(format!("Field '{}' is required...", param_name).as_str()))
+        Err(self.missing_param_error(
+            param_name.to_string(),
+            format!("Field '{}' is required...", param_name).as_str(),
+        ))
     }
 
     pub fn try_get_named_param(&self, param_name: &str) -> Option<&TokensObject> {
@@ -215,19 +653,47 @@ impl TokensObject {
         }
     }
 
+    /// Positional values are the unnamed ones before the named parameters. E.g.: #[route("/users/{id}", method: GET)]
+    /// A single unnamed value is the positional value with index 0
+    pub fn get_positional_params(&self) -> &[TokensObject] {
+        match self {
+            Self::Value(OptionalObjectValue::SingleValue(_)) => std::slice::from_ref(self),
+            Self::Object { positional, .. } => positional,
+            _ => &[],
+        }
+    }
+
+    pub fn try_get_positional(&self, index: usize) -> Option<&TokensObject> {
+        self.get_positional_params().get(index)
+    }
+
+    pub fn get_positional(&self, index: usize) -> Result<&TokensObject, TypesReaderError> {
+        match self.try_get_positional(index) {
+            Some(value) => Ok(value),
+            None => Err(self.missing_param_error(
+                format!("#{}", index),
+                format!("Positional parameter #{} is required...", index).as_str(),
+            )),
+        }
+    }
+
     pub fn try_get_value_from_single_or_named(
         &self,
         param_name: &str,
-    ) -> Result<Option<&OptionalObjectValue>, syn::Error> {
+    ) -> Result<Option<&OptionalObjectValue>, TypesReaderError> {
         match self {
             Self::Value(value) => return Ok(Some(value)),
-            Self::Object { .. } => match self {
-                Self::Object { items, .. } => match items.get(param_name) {
-                    Some(value) => return Ok(Some(value.unwrap_as_value()?)),
-                    None => {}
-                },
-                _ => {}
-            },
+            Self::Object {
+                items, positional, ..
+            } => {
+                if let Some(value) = items.get(param_name) {
+                    return Ok(Some(value.unwrap_as_value()?));
+                }
+
+                if let Some(value) = positional.first() {
+                    return Ok(Some(value.unwrap_as_value()?));
+                }
+            }
             _ => {}
         }
 
@@ -241,37 +707,87 @@ impl TokensObject {
         }
     }
 
+    /// Finds the name the parameter is given with: the name itself or one of its aliases.
+    /// If the parameter is absent - the name itself. If it is given with several names - an error
+    pub fn resolve_param_name<'n>(
+        &self,
+        param_name: &'n str,
+        aliases: &[&'n str],
+    ) -> Result<&'n str, TypesReaderError> {
+        let items = match self {
+            Self::Object { items, .. } => items,
+            _ => return Ok(param_name),
+        };
+
+        let mut given_names = std::iter::once(param_name)
+            .chain(aliases.iter().copied())
+            .filter(|name| items.contains_key(name));
+
+        let first_name = match given_names.next() {
+            Some(first_name) => first_name,
+            None => return Ok(param_name),
+        };
+
+        let mut errors = ErrorsAccumulator::new();
+
+        for name in given_names {
+            let mut err = syn::Error::new_spanned(
+                items.get_key_tokens(name).unwrap(),
+                format!(
+                    "Parameter '{}' is given as '{}' and as '{}'",
+                    param_name, first_name, name
+                ),
+            );
+            err.combine(syn::Error::new_spanned(
+                items.get_key_tokens(first_name).unwrap(),
+                format!("Parameter '{}' is first given here", param_name),
+            ));
+
+            errors.push(TypesReaderError::new(
+                ErrorKind::DuplicateParam {
+                    name: param_name.to_string(),
+                },
+                err,
+            ));
+        }
+
+        errors.into_result(first_name)
+    }
+
     pub fn get_value_from_single_or_named(
         &self,
         param_name: &str,
-    ) -> Result<&OptionalObjectValue, syn::Error> {
+    ) -> Result<&OptionalObjectValue, TypesReaderError> {
         match self {
             TokensObject::Value(value) => {
                 return Ok(value);
             }
-            TokensObject::Object { items, .. } => match items.get(param_name) {
-                Some(value) => return Ok(value.unwrap_as_value()?),
+            TokensObject::Object {
+                items, positional, ..
+            } => match items.get(param_name).or(positional.first()) {
+                Some(value) => return value.unwrap_as_value(),
                 None => {
-                    return Err(self.throw_error_at_param_token(
+                    return Err(self.missing_param_error(
+                        param_name.to_string(),
                         format!("Field '{}' is required.....", param_name).as_str(),
                     ))
                 }
             },
             TokensObject::Vec { .. } => {
-                return Err(self.throw_error_at_param_token("Can not get value. Value is array"))
+                return Err(self.type_mismatch_error("value", "Can not get value. Value is array"))
             }
         }
     }
 
-    pub fn unwrap_as_value(&self) -> Result<&OptionalObjectValue, syn::Error> {
+    pub fn unwrap_as_value(&self) -> Result<&OptionalObjectValue, TypesReaderError> {
         match self {
             TokensObject::Value(value) => Ok(value),
 
             TokensObject::Object { .. } => {
-                Err(self.throw_error_at_param_token("Can not get value. Value is object"))
+                Err(self.type_mismatch_error("value", "Can not get value. Value is object"))
             }
             TokensObject::Vec { .. } => {
-                Err(self.throw_error_at_param_token("Can not get value. Value is array"))
+                Err(self.type_mismatch_error("value", "Can not get value. Value is array"))
             }
         }
     }
@@ -281,7 +797,9 @@ impl TokensObject {
             Self::Value(value) => value.get_len(),
 
             Self::Vec { items, .. } => items.len(),
-            Self::Object { items, .. } => items.len(),
+            Self::Object {
+                items, positional, ..
+            } => positional.len() + items.len(),
         }
     }
 
@@ -292,91 +810,312 @@ impl TokensObject {
         }
     }
 
-    pub fn unwrap_as_object(&self) -> &HashMap<String, TokensObject> {
+    pub fn unwrap_as_object(&self) -> &ObjectItems {
         match self {
             Self::Object { items, .. } => items,
             _ => panic!("Can not unwrap as object"),
         }
     }
 
-    fn read_value(param_name: syn::Ident, token_value: NextToken) -> Result<Self, syn::Error> {
-        let next_token = match token_value.try_unwrap_as_value() {
-            Ok(token_value) => {
-                return Ok(Self::Value(OptionalObjectValue::Value {
-                    name: param_name,
-                    value: token_value.try_into()?,
-                }))
-            }
+    fn read_value(
+        param_name: syn::Ident,
+        token_reader: &mut TokensReader,
+        policy: DuplicateParamPolicy,
+    ) -> Result<Self, TypesReaderError> {
+        let value_tokens = Self::read_value_tokens(token_reader);
+
+        if value_tokens.is_empty() {
+            return Err(TypesReaderError::new_spanned(
+                ErrorKind::InvalidSyntax,
+                param_name,
+                "Value is expected",
+            ));
+        }
+
+        if let [TokenTree::Group(group)] = value_tokens.as_slice() {
+            return Self::read_group_value(
+                param_name,
+                TokensReader::new(group.stream()),
+                group.delimiter(),
+                policy,
+            );
+        }
+
+        Ok(Self::Value(OptionalObjectValue::Value {
+            name: param_name,
+            value: Self::read_object_value(value_tokens)?,
+        }))
+    }
+
+    fn read_object_value(value_tokens: Vec<TokenTree>) -> Result<ObjectValue, syn::Error> {
+        let is_simple_value = Self::is_simple_value(&value_tokens);
+
+        let token_stream: TokenStream = value_tokens.into_iter().collect();
+
+        if !is_simple_value {
+            Self::check_raw_tokens(&token_stream)?;
+            return Ok(ObjectValue::RawTokens(RawTokensValue::new(token_stream)));
+        }
+
+        let mut value_reader = TokensReader::new(token_stream);
+
+        if let Some(mut first_segment) = value_reader.try_read_path_separator() {
+            let ident = match value_reader.read_next_token()?.try_unwrap_into_ident() {
+                Ok(ident) => ident,
+                Err(next_token) => {
+                    return Err(next_token.throw_error("Expected path segment after '::'"));
+                }
+            };
+
+            first_segment.extend(ident.into_token_stream());
+
+            let path = PathValue::read(first_segment, &mut value_reader)?;
+            return Ok(ObjectValue::Path(path));
+        }
+
+        let next_token = match value_reader.read_next_token()?.try_unwrap_as_value() {
+            Ok(token_value) => return token_value.try_into(),
             Err(next_token) => next_token,
         };
 
-        let next_token = match next_token.try_unwrap_into_ident() {
+        match next_token.try_unwrap_into_ident() {
             Ok(ident) => {
-                return Ok(Self::Value(OptionalObjectValue::Value {
-                    name: param_name,
-                    value: ident.try_into()?,
-                }))
+                if value_reader.is_next_path_separator() {
+                    let path = PathValue::read(ident.into_token_stream(), &mut value_reader)?;
+                    Ok(ObjectValue::Path(path))
+                } else {
+                    ident.try_into()
+                }
             }
-            Err(next_token) => next_token,
-        };
+            Err(next_token) => Err(next_token.throw_error("Invalid value to read")),
+        }
+    }
 
-        let next_token = match next_token.try_unwrap_into_group(None) {
-            Ok((group_tokens, delimiter)) => {
-                return Self::read_group_value(param_name, group_tokens, delimiter)
+    // Raw tokens are a type, an expression or a lifetime: `a => b` or `1 2` is a typo, not a value
+    fn check_raw_tokens(token_stream: &TokenStream) -> Result<(), syn::Error> {
+        if let Some(TokenTree::Punct(punct)) = token_stream.clone().into_iter().next() {
+            if !VALUE_START_SYMBOLS.contains(&punct.as_char()) {
+                return Err(syn::Error::new(
+                    punct.span(),
+                    format!("Value can not start with '{}'", punct.as_char()),
+                ));
             }
-            Err(next_token) => next_token,
-        };
+        }
+
+        if syn::parse2::<syn::Type>(token_stream.clone()).is_err()
+            && syn::parse2::<syn::Expr>(token_stream.clone()).is_err()
+            && syn::parse2::<syn::Lifetime>(token_stream.clone()).is_err()
+        {
+            return Err(syn::Error::new_spanned(
+                token_stream,
+                "Value should be a literal, a path, a type or an expression",
+            ));
+        }
 
-        Err(next_token.throw_error("Invalid value to read"))
+        Ok(())
     }
 
-    fn read_group_value(
-        param_name: syn::Ident,
-        group_tokens: TokensReader,
-        delimiter: proc_macro2::Delimiter,
-    ) -> Result<Self, syn::Error> {
-        match delimiter {
-            proc_macro2::Delimiter::Bracket => {
-                let (items, token_stream) = Self::parse_as_array(param_name, group_tokens)?;
-                Ok(Self::Vec {
-                    token_stream,
-                    items,
-                })
-            }
-            proc_macro2::Delimiter::Brace | proc_macro2::Delimiter::Parenthesis => {
-                Self::new(group_tokens)
+    // A literal, a negative number, an ident or a path. Anything else is kept as raw tokens
+    fn is_simple_value(value_tokens: &[TokenTree]) -> bool {
+        match value_tokens {
+            [TokenTree::Literal(_)] => true,
+            [TokenTree::Punct(punct), TokenTree::Literal(_)] => punct.as_char() == '-',
+            [TokenTree::Ident(_), path_tail @ ..] => Self::is_path_tail(path_tail),
+            // Path with a leading colon: ::std::string::String
+            [TokenTree::Punct(first), TokenTree::Punct(second), TokenTree::Ident(_), path_tail @ ..] => {
+                Self::is_path_separator(first, second) && Self::is_path_tail(path_tail)
             }
-            proc_macro2::Delimiter::None => Err(group_tokens
-                .throw_error("Value can not be parsed from a group of tokens with no delimiter")),
+            _ => false,
         }
     }
 
-    pub fn unwrap_any_value_as_str(&self) -> Result<&dyn AnyValueAsStr, syn::Error> {
-        let value = self.unwrap_as_value()?;
-        Ok(value)
+    // The rest of the path after the first segment: (:: Ident)*
+    fn is_path_tail(path_tail: &[TokenTree]) -> bool {
+        path_tail.chunks(3).all(|segment| match segment {
+            [TokenTree::Punct(first), TokenTree::Punct(second), TokenTree::Ident(_)] => {
+                Self::is_path_separator(first, second)
+            }
+            _ => false,
+        })
     }
 
-    pub fn parse_as_array(
-        param_name: syn::Ident,
-        mut token_reader: TokensReader,
-    ) -> Result<(Vec<TokensObject>, TokenStream), syn::Error> {
-        let mut result: Vec<TokensObject> = Vec::new();
+    fn is_path_separator(first: &proc_macro2::Punct, second: &proc_macro2::Punct) -> bool {
+        first.as_char() == ':' && first.spacing() == Spacing::Joint && second.as_char() == ':'
+    }
 
-        while let Some(mut token) = token_reader.try_read_next_token()? {
-            if token.if_spacing(Some(&SPACE_SYMBOLS)) {
-                let next_token = token_reader.try_read_next_token()?;
-                if next_token.is_none() {
-                    break;
+    // Content of the attribute is a single unnamed value. E.g.: #[my_attr("value")] or #[my_attr(Vec<u8>)]
+    fn is_single_value(token_reader: &TokensReader) -> bool {
+        match (
+            token_reader.try_peek_token_tree(0),
+            token_reader.try_peek_token_tree(1),
+        ) {
+            // String literal name: "x-id": "1"
+            (Some(token @ TokenTree::Literal(_)), Some(TokenTree::Punct(punct))) => {
+                let is_name = match punct.as_char() {
+                    ':' => punct.spacing() == Spacing::Alone,
+                    '=' => true,
+                    _ => false,
+                };
+
+                !(is_name && try_read_string_key(token).is_some())
+            }
+            (Some(TokenTree::Literal(_)), _) => true,
+            (Some(TokenTree::Punct(_)), _) => true,
+            (Some(TokenTree::Ident(_)), Some(TokenTree::Punct(punct))) => match punct.as_char() {
+                ':' => punct.spacing() == Spacing::Joint,
+                '=' | ',' | ';' => false,
+                // Kebab-case name: content-type: "json"
+                '-' => Self::get_param_name_len(token_reader) == 1,
+                _ => true,
+            },
+            _ => false,
+        }
+    }
+
+    /// Reads the tokens of a single value - up to the `,` or `;` which separates it from the next parameter.
+    /// A separator may be a part of the value as well: `HashMap<String, u8>` or `|a, b| a + b`.
+    fn read_value_tokens(token_reader: &mut TokensReader) -> Vec<TokenTree> {
+        let value_end = Self::find_value_end(token_reader);
+
+        (0..value_end)
+            .filter_map(|_| token_reader.try_get_next_token())
+            .collect()
+    }
+
+    fn find_value_end(token_reader: &TokensReader) -> usize {
+        let mut angle_brackets_depth = 0;
+        let mut in_closure_params = false;
+        let mut first_nested_separator = None;
+        let mut prev_token: Option<&TokenTree> = None;
+        let mut index = 0;
+
+        while let Some(token) = token_reader.try_peek_token_tree(index) {
+            if let TokenTree::Punct(punct) = token {
+                match punct.as_char() {
+                    '|' if in_closure_params => in_closure_params = false,
+                    // Parameters of the closure: |a, b| a + b or move |a, b| a + b
+                    '|' if punct.spacing() == Spacing::Alone
+                        && Self::is_closure_start(token_reader, index) =>
+                    {
+                        in_closure_params = true
+                    }
+                    '<' => angle_brackets_depth += 1,
+                    '>' => {
+                        // `->` and `=>` do not close the generic arguments
+                        let is_arrow = matches!(prev_token, Some(TokenTree::Punct(prev))
+                            if (prev.as_char() == '-' || prev.as_char() == '=')
+                                && prev.spacing() == Spacing::Joint);
+
+                        if !is_arrow && angle_brackets_depth > 0 {
+                            angle_brackets_depth -= 1;
+                        }
+                    }
+                    ',' | ';' if !in_closure_params => {
+                        if angle_brackets_depth == 0 {
+                            break;
+                        }
+
+                        first_nested_separator.get_or_insert(index);
+                    }
+                    _ => {}
                 }
+            }
+
+            prev_token = Some(token);
+            index += 1;
+        }
+
+        // The separator after an unclosed `<` belongs either to the generic arguments (`HashMap<String, u8>`)
+        // or to the next parameter if the `<` is a comparison (`|v| v.len() < 10, max: 5`)
+        if let Some(first_nested_separator) = first_nested_separator {
+            let tokens: Vec<TokenTree> = (0..index)
+                .filter_map(|index| token_reader.try_peek_token_tree(index).cloned())
+                .collect();
+
+            if !Self::parses_as::<syn::Type>(&tokens) && !Self::parses_as::<syn::Expr>(&tokens) {
+                return first_nested_separator;
+            }
+        }
+
+        index
+    }
+
+    // Only `move` and `async` may go before the parameters of the closure
+    fn is_closure_start(token_reader: &TokensReader, index: usize) -> bool {
+        (0..index).all(|index| {
+            matches!(token_reader.try_peek_token_tree(index), Some(TokenTree::Ident(ident))
+                if ident == "move" || ident == "async")
+        })
+    }
+
+    fn parses_as<T: syn::parse::Parse>(tokens: &[TokenTree]) -> bool {
+        let token_stream: TokenStream = tokens.iter().cloned().collect();
+        syn::parse2::<T>(token_stream).is_ok()
+    }
+
+    fn read_group_value(
+        param_name: syn::Ident,
+        group_tokens: TokensReader,
+        delimiter: proc_macro2::Delimiter,
+        policy: DuplicateParamPolicy,
+    ) -> Result<Self, TypesReaderError> {
+        match delimiter {
+            proc_macro2::Delimiter::Bracket => {
+                let (items, token_stream) =
+                    Self::parse_as_array_with_policy(param_name, group_tokens, policy)?;
+                Ok(Self::Vec {
+                    token_stream,
+                    items,
+                })
+            }
+            proc_macro2::Delimiter::Brace | proc_macro2::Delimiter::Parenthesis => {
+                Self::new_with_policy(group_tokens, policy)
+            }
+            proc_macro2::Delimiter::None => Err(TypesReaderError::new(
+                ErrorKind::InvalidSyntax,
+                group_tokens.throw_error(
+                    "Value can not be parsed from a group of tokens with no delimiter",
+                ),
+            )),
+        }
+    }
+
+    pub fn unwrap_any_value_as_str(&self) -> Result<&dyn AnyValueAsStr, TypesReaderError> {
+        let value = self.unwrap_as_value()?;
+        Ok(value)
+    }
+
+    pub fn parse_as_array(
+        param_name: syn::Ident,
+        token_reader: TokensReader,
+    ) -> Result<(Vec<TokensObject>, TokenStream), TypesReaderError> {
+        Self::parse_as_array_with_policy(param_name, token_reader, DuplicateParamPolicy::default())
+    }
+
+    pub fn parse_as_array_with_policy(
+        param_name: syn::Ident,
+        mut token_reader: TokensReader,
+        policy: DuplicateParamPolicy,
+    ) -> Result<(Vec<TokensObject>, TokenStream), TypesReaderError> {
+        let mut result: Vec<TokensObject> = Vec::new();
+        let mut errors = ErrorsAccumulator::new();
 
-                token = next_token.unwrap();
+        while let Some(token) = token_reader.try_peek_token_tree(0) {
+            if let TokenTree::Punct(punct) = token {
+                if SPACE_SYMBOLS.contains(&punct.as_char()) {
+                    token_reader.try_get_next_token();
+                    continue;
+                }
             }
 
-            let param_value = Self::read_value(param_name.clone(), token)?;
-            result.push(param_value);
+            let param_value = Self::read_value(param_name.clone(), &mut token_reader, policy);
+
+            if let Some(param_value) = errors.handle(param_value) {
+                result.push(param_value);
+            }
         }
 
-        Ok((result, token_reader.into_token_stream()))
+        errors.into_result((result, token_reader.into_token_stream()))
     }
 
     pub fn as_ref(&self) -> &Self {
@@ -385,7 +1124,7 @@ impl TokensObject {
 }
 
 impl TryInto<TokensObject> for proc_macro2::TokenStream {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<TokensObject, Self::Error> {
         TokensObject::new(self.into())
@@ -393,11 +1132,11 @@ impl TryInto<TokensObject> for proc_macro2::TokenStream {
 }
 
 impl<'s> TryInto<&'s ObjectValue> for &'s TokensObject {
-    type Error = syn::Error;
+    type Error = TypesReaderError;
 
     fn try_into(self) -> Result<&'s ObjectValue, Self::Error> {
-        let value = self.unwrap_as_value()?;
-        value.unwrap_value()
+        let value = self.unwrap_as_value()?.unwrap_value()?;
+        Ok(value)
     }
 }
 
@@ -407,11 +1146,124 @@ impl AsRef<TokensObject> for TokensObject {
     }
 }
 
+struct DuplicatesTracker {
+    policy: DuplicateParamPolicy,
+    collected: HashSet<String>,
+}
+
+impl DuplicatesTracker {
+    fn new(policy: DuplicateParamPolicy) -> Self {
+        Self {
+            policy,
+            collected: HashSet::new(),
+        }
+    }
+
+    fn insert(
+        &mut self,
+        items: &mut ObjectItems,
+        id: String,
+        param_name: syn::Ident,
+        key_tokens: TokenStream,
+        value: TokensObject,
+    ) -> Result<(), TypesReaderError> {
+        let first_key_tokens = match items.get_key_tokens(&id) {
+            Some(first_key_tokens) => first_key_tokens,
+            None => {
+                items.insert_with_key_tokens(id, param_name, key_tokens, value);
+                return Ok(());
+            }
+        };
+
+        match self.policy {
+            DuplicateParamPolicy::Error => {
+                Err(duplicate_param_error(id, &key_tokens, first_key_tokens))
+            }
+            DuplicateParamPolicy::KeepFirst => Ok(()),
+            DuplicateParamPolicy::KeepLast => {
+                items.insert_with_key_tokens(id, param_name, key_tokens, value);
+                Ok(())
+            }
+            DuplicateParamPolicy::CollectAsVec => {
+                let first_ident = items.get_key_ident(&id).unwrap();
+                let token_stream = quote::quote!(#first_ident);
+                let current_value = items.get_mut(&id).unwrap();
+
+                if !self.collected.contains(&id) {
+                    let first_value = std::mem::replace(
+                        current_value,
+                        TokensObject::Vec {
+                            token_stream,
+                            items: Vec::new(),
+                        },
+                    );
+
+                    if let TokensObject::Vec { items, .. } = current_value {
+                        items.push(first_value);
+                    }
+
+                    self.collected.insert(id.clone());
+                }
+
+                if let TokensObject::Vec { items, .. } = current_value {
+                    items.push(value);
+                }
+
+                items.add_repeated_key(id, key_tokens);
+
+                Ok(())
+            }
+        }
+    }
+}
+
+fn duplicate_param_error(
+    id: String,
+    key_tokens: &TokenStream,
+    first_key_tokens: &TokenStream,
+) -> TypesReaderError {
+    let mut err = syn::Error::new_spanned(key_tokens, format!("Duplicate parameter '{}'", id));
+    err.combine(syn::Error::new_spanned(
+        first_key_tokens,
+        format!("Parameter '{}' is first defined here", id),
+    ));
+    TypesReaderError::new(ErrorKind::DuplicateParam { name: id }, err)
+}
+
+fn try_read_string_key(token: &TokenTree) -> Option<syn::LitStr> {
+    match token {
+        TokenTree::Literal(_) => syn::parse2::<syn::LitStr>(token.clone().into()).ok(),
+        _ => None,
+    }
+}
+
+// The ident of the string literal name points to the literal
+fn string_key_to_ident(name: &syn::LitStr) -> syn::Ident {
+    let mut ident: String = name
+        .value()
+        .chars()
+        .map(|c| {
+            if unicode_ident::is_xid_continue(c) {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    if !ident.starts_with(|c: char| unicode_ident::is_xid_start(c) || c == '_') {
+        ident.insert(0, '_');
+    }
+
+    syn::Ident::new(&ident, name.span())
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::TokensObject;
+    use crate::{DuplicateParamPolicy, ErrorKind, TokensObject};
+    use quote::ToTokens;
 
     #[test]
     fn test_value_in_named_param_reading_by_single_or_by_name_but_topic() {
@@ -490,7 +1342,8 @@ mod tests {
                 .unwrap()
                 .as_number()
                 .unwrap()
-                .as_i32(),
+                .as_i32()
+                .unwrap(),
             5
         );
 
@@ -534,7 +1387,8 @@ mod tests {
                 .unwrap()
                 .as_number()
                 .unwrap()
-                .as_i32(),
+                .as_i32()
+                .unwrap(),
             -1
         );
 
@@ -559,7 +1413,7 @@ mod tests {
 
         let value = tokens_object.unwrap_as_value().unwrap();
 
-        assert_eq!(value.as_number().unwrap().as_i32(), -256);
+        assert_eq!(value.as_number().unwrap().as_i32().unwrap(), -256);
     }
 
     #[test]
@@ -572,7 +1426,7 @@ mod tests {
 
         let value = tokens_object.unwrap_as_value().unwrap();
 
-        assert_eq!(value.as_number().unwrap().as_i32(), 256);
+        assert_eq!(value.as_number().unwrap().as_i32().unwrap(), 256);
     }
 
     #[test]
@@ -585,7 +1439,7 @@ mod tests {
 
         let value = params_list.unwrap_as_value().unwrap();
 
-        assert_eq!(value.as_double().unwrap().as_f64(), 256.34);
+        assert_eq!(value.as_double().unwrap().as_f64().unwrap(), 256.34);
     }
 
     #[test]
@@ -598,7 +1452,7 @@ mod tests {
 
         let value = params_list.unwrap_as_value().unwrap();
 
-        assert_eq!(value.as_double().unwrap().as_f64(), -256.34);
+        assert_eq!(value.as_double().unwrap().as_f64().unwrap(), -256.34);
     }
 
     #[test]
@@ -693,7 +1547,8 @@ mod tests {
                 .unwrap()
                 .as_number()
                 .unwrap()
-                .as_i32(),
+                .as_i32()
+                .unwrap(),
             1
         );
 
@@ -742,7 +1597,8 @@ mod tests {
                 .unwrap()
                 .as_number()
                 .unwrap()
-                .as_i32(),
+                .as_i32()
+                .unwrap(),
             1
         );
 
@@ -766,7 +1622,8 @@ mod tests {
                 .unwrap()
                 .as_number()
                 .unwrap()
-                .as_i32(),
+                .as_i32()
+                .unwrap(),
             2
         );
 
@@ -785,8 +1642,60 @@ mod tests {
     }
 
     #[test]
-    fn test_single_value_with_extra_named_params_returns_error() {
-        let src = r#""my-value", with_expires:true"#;
+    fn test_positional_and_named_params() {
+        let src = r#""/users/{id}", 5, method: GET, auth"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        assert_eq!(params_list.get_positional_params().len(), 2);
+
+        let path: &str = params_list.get_positional(0).unwrap().try_into().unwrap();
+        assert_eq!(path, "/users/{id}");
+
+        let limit: u8 = params_list.get_positional(1).unwrap().try_into().unwrap();
+        assert_eq!(limit, 5);
+
+        assert!(params_list.try_get_positional(2).is_none());
+        assert!(params_list.get_positional(2).is_err());
+
+        let method: syn::Ident = params_list
+            .get_named_param("method")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(method.to_string(), "GET");
+
+        assert!(params_list.has_param("auth"));
+
+        let path = params_list.get_value_from_single_or_named("path").unwrap();
+        assert_eq!(
+            path.unwrap_value().unwrap().as_string().unwrap().as_str(),
+            "/users/{id}"
+        );
+    }
+
+    #[test]
+    fn test_single_value_is_positional_param_zero() {
+        let src = r#""my-value""#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let single_value = TokensObject::new(token_stream.into()).unwrap();
+
+        assert!(single_value.unwrap_as_value().is_ok());
+
+        let value: &str = single_value.get_positional(0).unwrap().try_into().unwrap();
+        assert_eq!(value, "my-value");
+
+        assert!(single_value.check_for_unknown_positional_params(1).is_ok());
+        assert!(single_value.check_for_unknown_positional_params(0).is_err());
+    }
+
+    #[test]
+    fn test_positional_param_after_named_returns_error() {
+        let src = r#"with_expires:true, "my-value""#;
 
         let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
 
@@ -794,7 +1703,9 @@ mod tests {
 
         assert!(result.is_err());
         let err = result.unwrap_err();
-        assert!(err.to_string().contains("all parameters must be named"));
+        assert!(err
+            .to_string()
+            .contains("Positional parameters must go before named parameters"));
     }
 
     #[test]
@@ -848,7 +1759,8 @@ mod tests {
                 .unwrap()
                 .as_number()
                 .unwrap()
-                .as_i32(),
+                .as_i32()
+                .unwrap(),
             1
         );
 
@@ -910,4 +1822,1267 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_duplicate_param_returns_error() {
+        let src = r#"id: 1, name: "a", id: 2"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let result = TokensObject::new(token_stream.into());
+
+        let err = result.unwrap_err();
+
+        let messages: Vec<String> = err.into_iter().map(|itm| itm.to_string()).collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "Duplicate parameter 'id'".to_string(),
+                "Parameter 'id' is first defined here".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_flag_returns_error() {
+        let src = r#"flag, flag"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        assert!(TokensObject::new(token_stream.into()).is_err());
+    }
+
+    #[test]
+    fn test_duplicate_param_inside_nested_object_returns_error() {
+        let src = r#"object_param: {a: 1, a: 2}"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        assert!(TokensObject::new(token_stream.into()).is_err());
+    }
+
+    #[test]
+    fn test_duplicate_param_keep_first() {
+        let src = r#"id: 1, id: 2"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list =
+            TokensObject::new_with_policy(token_stream.into(), DuplicateParamPolicy::KeepFirst)
+                .unwrap();
+
+        let value: i32 = params_list
+            .get_named_param("id")
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert_eq!(value, 1);
+    }
+
+    #[test]
+    fn test_duplicate_param_keep_last() {
+        let src = r#"id: 1, id: 2"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list =
+            TokensObject::new_with_policy(token_stream.into(), DuplicateParamPolicy::KeepLast)
+                .unwrap();
+
+        let value: i32 = params_list
+            .get_named_param("id")
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert_eq!(value, 2);
+    }
+
+    #[test]
+    fn test_duplicate_param_collect_as_vec() {
+        let src = r#"id: 1, name: "a", id: 2, id: 3"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list =
+            TokensObject::new_with_policy(token_stream.into(), DuplicateParamPolicy::CollectAsVec)
+                .unwrap();
+
+        let items = params_list
+            .get_named_param("id")
+            .unwrap()
+            .unwrap_as_vec()
+            .unwrap();
+
+        let values: Vec<i32> = items.iter().map(|itm| itm.try_into().unwrap()).collect();
+
+        assert_eq!(values, vec![1, 2, 3]);
+
+        let name: &str = params_list
+            .get_named_param("name")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(name, "a");
+    }
+
+    #[test]
+    fn test_repeated_params_are_checked() {
+        let src = r#"alias: "a", rename: "b", alias: "c", rename: "d""#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list =
+            TokensObject::new_with_policy(token_stream.into(), DuplicateParamPolicy::CollectAsVec)
+                .unwrap();
+
+        assert!(params_list
+            .check_for_repeated_params(&["alias", "rename"])
+            .is_ok());
+
+        let err = params_list
+            .check_for_repeated_params(&["alias"])
+            .unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::DuplicateParam {
+                name: "rename".to_string()
+            }
+        );
+
+        let messages: Vec<String> = err.into_iter().map(|err| err.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Duplicate parameter 'rename'",
+                "Parameter 'rename' is first defined here"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_param_is_reported_in_source_order() {
+        let src = r#"known: 1, second_unknown: 2, first_unknown: 3"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let err = params_list
+            .check_for_unknown_params(&["known"])
+            .unwrap_err();
+
+        assert!(err
+            .to_string()
+            .starts_with("Unknown parameter 'second_unknown'"));
+    }
+
+    #[test]
+    fn test_string_escapes_are_decoded() {
+        let src = r#"msg: "a\nb\t\"c\"\\ \x41 \u{1F600}""#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let msg: &str = params_list
+            .get_named_param("msg")
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert_eq!(msg, "a\nb\t\"c\"\\ A \u{1F600}");
+    }
+
+    #[test]
+    fn test_raw_string() {
+        let src = r####"pattern: r#"^\d+"quoted"$"#, plain: r"C:\temp""####;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let pattern: &str = params_list
+            .get_named_param("pattern")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(pattern, r#"^\d+"quoted"$"#);
+
+        let plain: &str = params_list
+            .get_named_param("plain")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(plain, r"C:\temp");
+    }
+
+    #[test]
+    fn test_byte_string() {
+        let src = r#"magic: b"\x7FELF", raw: br"\n""#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let magic: &[u8] = params_list
+            .get_named_param("magic")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(magic, b"\x7FELF");
+
+        let raw: Vec<u8> = params_list
+            .get_named_param("raw")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(raw, b"\\n".to_vec());
+
+        let value = params_list
+            .get_named_param("magic")
+            .unwrap()
+            .unwrap_as_value()
+            .unwrap();
+        assert!(value.as_string().is_err());
+    }
+
+    #[test]
+    fn test_char() {
+        let src = r#"separator: ',', new_line: '\n', emoji: '\u{1F600}'"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let separator: char = params_list
+            .get_named_param("separator")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(separator, ',');
+
+        let new_line: char = params_list
+            .get_named_param("new_line")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(new_line, '\n');
+
+        let emoji: char = params_list
+            .get_named_param("emoji")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(emoji, '\u{1F600}');
+
+        let emoji = params_list
+            .get_named_param("emoji")
+            .unwrap()
+            .unwrap_as_value()
+            .unwrap()
+            .as_char()
+            .unwrap();
+        assert_eq!(emoji.to_token_stream().to_string(), r"'\u{1F600}'");
+    }
+
+    #[test]
+    fn test_c_string() {
+        let src = r#"name: c"hello\tworld""#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let name: &std::ffi::CStr = params_list
+            .get_named_param("name")
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert_eq!(name.to_bytes(), b"hello\tworld");
+    }
+
+    #[test]
+    fn test_text_values_as_str_and_as_source() {
+        let src = r#"text: "a\tb", bytes: b"a\tb", letter: '\t', name: c"a\tb""#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let get_value = |name: &str| {
+            params_list
+                .get_named_param(name)
+                .unwrap()
+                .unwrap_as_value()
+                .unwrap()
+                .unwrap_value()
+                .unwrap()
+        };
+
+        let text = get_value("text").as_string().unwrap();
+        assert_eq!(text.as_str(), "a\tb");
+        assert_eq!(text.as_source(), r#""a\tb""#);
+
+        let bytes = get_value("bytes").as_byte_string().unwrap();
+        assert_eq!(bytes.as_str(), "a\tb");
+        assert_eq!(bytes.as_source(), r#"b"a\tb""#);
+
+        let letter = get_value("letter").as_char().unwrap();
+        assert_eq!(letter.as_str(), "\t");
+        assert_eq!(letter.as_source(), r"'\t'");
+
+        let name = get_value("name").as_c_string().unwrap();
+        assert_eq!(name.as_str(), "a\tb");
+        assert_eq!(name.as_source(), r#"c"a\tb""#);
+
+        for name in ["text", "bytes", "letter", "name"] {
+            let expected = if name == "letter" { "\t" } else { "a\tb" };
+            assert_eq!(get_value(name).any_value_as_str(), expected);
+        }
+    }
+
+    #[test]
+    fn test_negative_string_is_an_error() {
+        let src = r#"name: -"value""#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let err = TokensObject::new(token_stream.into()).unwrap_err();
+
+        assert_eq!(err.to_string(), "Only numbers can be negative");
+    }
+
+    #[test]
+    fn test_number_does_not_fit_into_type() {
+        let src = r#"id: 300, negative: -1"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let id = params_list.get_named_param("id").unwrap();
+
+        let err = TryInto::<u8>::try_into(id).unwrap_err();
+        assert_eq!(err.to_string(), "value 300 does not fit into u8 (0..=255)");
+
+        let value: u16 = id.try_into().unwrap();
+        assert_eq!(value, 300);
+
+        let negative = params_list.get_named_param("negative").unwrap();
+
+        let err = TryInto::<u64>::try_into(negative).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "value -1 does not fit into u64 (0..=18446744073709551615)"
+        );
+
+        let value: i8 = negative.try_into().unwrap();
+        assert_eq!(value, -1);
+    }
+
+    #[test]
+    fn test_wrapping_and_saturating_numbers() {
+        let src = r#"id: 300, negative: -1"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let id = params_list
+            .get_named_param("id")
+            .unwrap()
+            .unwrap_as_value()
+            .unwrap()
+            .as_number()
+            .unwrap();
+
+        assert_eq!(id.wrapping_as_u8(), 44);
+        assert_eq!(id.saturating_as_u8(), 255);
+        assert_eq!(id.saturating_as_i8(), 127);
+
+        let negative = params_list
+            .get_named_param("negative")
+            .unwrap()
+            .unwrap_as_value()
+            .unwrap()
+            .as_number()
+            .unwrap();
+
+        assert_eq!(negative.wrapping_as_u64(), u64::MAX);
+        assert_eq!(negative.saturating_as_u64(), 0);
+        assert_eq!(negative.saturating_as_i16(), -1);
+    }
+
+    #[test]
+    fn test_integer_literal_forms() {
+        let src = r#"hex: 0xFF, octal: 0o755, binary: 0b1010, big: 1_000_000, suffixed: 10u8, negative_hex: -0x80"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let hex: u8 = params_list
+            .get_named_param("hex")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(hex, 0xFF);
+
+        let octal: u32 = params_list
+            .get_named_param("octal")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(octal, 0o755);
+
+        let binary: i32 = params_list
+            .get_named_param("binary")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(binary, 10);
+
+        let big: u64 = params_list
+            .get_named_param("big")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(big, 1_000_000);
+
+        let suffixed = params_list.get_named_param("suffixed").unwrap();
+        let value: u8 = suffixed.try_into().unwrap();
+        assert_eq!(value, 10);
+
+        let number = suffixed.unwrap_as_value().unwrap().as_number().unwrap();
+        assert_eq!(number.get_suffix(), Some("u8"));
+        assert_eq!(number.as_str(), "10u8");
+
+        let negative_hex: i8 = params_list
+            .get_named_param("negative_hex")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(negative_hex, i8::MIN);
+    }
+
+    #[test]
+    fn test_integer_suffix_must_match_type() {
+        let src = r#"id: 10u8"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let id = params_list.get_named_param("id").unwrap();
+
+        let err = TryInto::<u16>::try_into(id).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "value 10u8 has suffix u8, but u16 is expected"
+        );
+    }
+
+    #[test]
+    fn test_128_bit_integers() {
+        let src = r#"max: 340282366920938463463374607431768211455, min: -170141183460469231731687303715884105728"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let max: u128 = params_list
+            .get_named_param("max")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(max, u128::MAX);
+
+        let min: i128 = params_list
+            .get_named_param("min")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(min, i128::MIN);
+
+        let err =
+            TryInto::<i128>::try_into(params_list.get_named_param("max").unwrap()).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("value 340282366920938463463374607431768211455 does not fit into i128"));
+    }
+
+    #[test]
+    fn test_integer_literal_is_too_large() {
+        let src = r#"id: 340282366920938463463374607431768211456"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let err = TokensObject::new(token_stream.into()).unwrap_err();
+
+        assert_eq!(err.to_string(), "Integer literal is too large");
+    }
+
+    #[test]
+    fn test_float_literal_forms() {
+        let src = r#"small: 1e-3, big: 2.5E10, grouped: 1_000.5, single: 1.0f32, negative: -1.5e2"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let small: f64 = params_list
+            .get_named_param("small")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(small, 1e-3);
+
+        let big: f64 = params_list
+            .get_named_param("big")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(big, 2.5e10);
+
+        let grouped: f64 = params_list
+            .get_named_param("grouped")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(grouped, 1000.5);
+
+        let single = params_list.get_named_param("single").unwrap();
+        let value: f32 = single.try_into().unwrap();
+        assert_eq!(value, 1.0);
+
+        let double = single.unwrap_as_value().unwrap().as_double().unwrap();
+        assert_eq!(double.get_suffix(), Some("f32"));
+        assert_eq!(
+            double.as_f64().unwrap_err().to_string(),
+            "value 1.0f32 has suffix f32, but f64 is expected"
+        );
+
+        let negative: f64 = params_list
+            .get_named_param("negative")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(negative, -150.0);
+    }
+
+    #[test]
+    fn test_integer_coerces_to_float() {
+        let src = r#"ratio: 1, negative: -3, precise: 16777217"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let ratio: f64 = params_list
+            .get_named_param("ratio")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(ratio, 1.0);
+
+        let negative: f32 = params_list
+            .get_named_param("negative")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(negative, -3.0);
+
+        let precise = params_list.get_named_param("precise").unwrap();
+
+        let value: f64 = precise.try_into().unwrap();
+        assert_eq!(value, 16777217.0);
+
+        let err = TryInto::<f32>::try_into(precise).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "value 16777217 can not be represented exactly as f32"
+        );
+    }
+
+    #[test]
+    fn test_float_out_of_range() {
+        let src = r#"huge: 1e400, negative: -1e400, single: 1e39"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let err =
+            TryInto::<f64>::try_into(params_list.get_named_param("huge").unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "value 1e400 does not fit into f64");
+
+        let err = params_list
+            .get_named_param("negative")
+            .unwrap()
+            .unwrap_as_value()
+            .unwrap()
+            .as_double()
+            .unwrap()
+            .as_f64()
+            .unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Overflow);
+
+        let single = params_list.get_named_param("single").unwrap();
+
+        let value: f64 = single.try_into().unwrap();
+        assert_eq!(value, 1e39);
+
+        let err = TryInto::<f32>::try_into(single).unwrap_err();
+        assert_eq!(err.to_string(), "value 1e39 does not fit into f32");
+
+        let huge = params_list
+            .get_named_param("huge")
+            .unwrap()
+            .unwrap_as_value()
+            .unwrap()
+            .as_double()
+            .unwrap();
+
+        let err = huge.as_u64().unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Overflow);
+        assert_eq!(
+            err.to_string(),
+            "value 1e400 does not fit into u64 (0..=18446744073709551615)"
+        );
+
+        let err = huge.as_i8().unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Overflow);
+    }
+
+    #[test]
+    fn test_float_to_integer_is_checked() {
+        let src = r#"whole: 200.0, fraction: 1.5, large: 1e10"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let get_double = |name: &str| {
+            params_list
+                .get_named_param(name)
+                .unwrap()
+                .unwrap_as_value()
+                .unwrap()
+                .as_double()
+                .unwrap()
+        };
+
+        assert_eq!(get_double("whole").as_u8().unwrap(), 200);
+        assert_eq!(
+            get_double("whole").as_i8().unwrap_err().to_string(),
+            "value 200.0 does not fit into i8 (-128..=127)"
+        );
+        assert_eq!(
+            get_double("fraction").as_u16().unwrap_err().to_string(),
+            "value 1.5 has a fractional part and can not be converted into u16"
+        );
+        assert_eq!(get_double("large").as_u64().unwrap(), 10_000_000_000);
+    }
+
+    #[test]
+    fn test_path_value() {
+        let src = r#"handler: crate::api::get_user, ty: std::string::String, name: get_user"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let handler: &syn::Path = params_list
+            .get_named_param("handler")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(handler.segments.len(), 3);
+        assert_eq!(
+            handler.segments.last().unwrap().ident.to_string(),
+            "get_user"
+        );
+
+        let ty = params_list
+            .get_named_param("ty")
+            .unwrap()
+            .unwrap_as_value()
+            .unwrap()
+            .unwrap_value()
+            .unwrap();
+        assert_eq!(ty.as_path().unwrap().as_str(), "std::string::String");
+        assert_eq!(ty.any_value_as_str(), "std::string::String");
+
+        let err = TryInto::<syn::Ident>::try_into(params_list.get_named_param("ty").unwrap())
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Expected ident, found path");
+
+        let name = params_list.get_named_param("name").unwrap();
+
+        let as_path: syn::Path = name.try_into().unwrap();
+        assert!(as_path.is_ident("get_user"));
+
+        let as_ident: syn::Ident = name.try_into().unwrap();
+        assert_eq!(as_ident.to_string(), "get_user");
+    }
+
+    #[test]
+    fn test_paths_in_array_and_as_single_value() {
+        let src = r#"handlers: [api::get, api::post]"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let handlers: Vec<String> = params_list
+            .get_named_param("handlers")
+            .unwrap()
+            .unwrap_as_vec()
+            .unwrap()
+            .iter()
+            .map(|itm| {
+                let path: &syn::Path = itm.try_into().unwrap();
+                path.segments.last().unwrap().ident.to_string()
+            })
+            .collect();
+
+        assert_eq!(handlers, vec!["get", "post"]);
+
+        let src = r#"crate::api::get_user"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let single_value = TokensObject::new(token_stream.into()).unwrap();
+
+        let path: syn::Path = (&single_value).try_into().unwrap();
+        assert_eq!(path.segments.len(), 3);
+    }
+
+    #[test]
+    fn test_path_with_leading_colon() {
+        let src =
+            r#"ty: ::std::string::String, handlers: [::api::get, api::post], name: ::get_user"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let ty = params_list.get_named_param("ty").unwrap();
+
+        let path: &syn::Path = ty.try_into().unwrap();
+        assert!(path.leading_colon.is_some());
+        assert_eq!(path.segments.len(), 3);
+
+        let value = ty.unwrap_as_value().unwrap().unwrap_value().unwrap();
+        assert_eq!(value.as_path().unwrap().as_str(), "::std::string::String");
+
+        let handlers = params_list
+            .get_named_param("handlers")
+            .unwrap()
+            .unwrap_as_vec()
+            .unwrap();
+        let first: &syn::Path = (&handlers[0]).try_into().unwrap();
+        assert!(first.leading_colon.is_some());
+
+        let name: syn::Path = params_list
+            .get_named_param("name")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert!(name.leading_colon.is_some());
+        assert_eq!(name.segments.len(), 1);
+
+        let src = r#"::crate_name::Type"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let single_value = TokensObject::new(token_stream.into()).unwrap();
+
+        let path: syn::Path = (&single_value).try_into().unwrap();
+        assert!(path.leading_colon.is_some());
+        assert_eq!(path.segments.len(), 2);
+    }
+    #[test]
+    fn test_types_and_expressions_as_raw_tokens() {
+        let src = r#"ty: Option<Vec<u8>>, map: HashMap<String, u8>, default: Vec::new(), check: |v| v.len() < 10, sum: |a, b| a + b, name: "test""#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let ty: syn::Type = params_list
+            .get_named_param("ty")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(ty.to_token_stream().to_string(), "Option < Vec < u8 > >");
+
+        let map: syn::Type = params_list
+            .get_named_param("map")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(map.to_token_stream().to_string(), "HashMap < String , u8 >");
+
+        let default: syn::Expr = params_list
+            .get_named_param("default")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(default.to_token_stream().to_string(), "Vec :: new ()");
+
+        let check: syn::ExprClosure = params_list
+            .get_named_param("check")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(check.inputs.len(), 1);
+
+        let sum: syn::ExprClosure = params_list
+            .get_named_param("sum")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(sum.inputs.len(), 2);
+
+        let name: &str = params_list
+            .get_named_param("name")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(name, "test");
+    }
+
+    #[test]
+    fn test_comparison_and_closure_before_next_param() {
+        let src = r#"less: a < b, greater: c > d, sum: move |a, b| a + b, name: "test""#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let less: syn::Expr = params_list
+            .get_named_param("less")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(less.to_token_stream().to_string(), "a < b");
+
+        let greater: syn::Expr = params_list
+            .get_named_param("greater")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(greater.to_token_stream().to_string(), "c > d");
+
+        let sum: syn::ExprClosure = params_list
+            .get_named_param("sum")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(sum.inputs.len(), 2);
+
+        let name: &str = params_list
+            .get_named_param("name")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(name, "test");
+    }
+
+    #[test]
+    fn test_broken_raw_tokens_are_rejected() {
+        let src = r#"a => b, x: 1 2, y = > 3, lifetime: 'a, ok: true"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let err = TokensObject::new(token_stream.into()).unwrap_err();
+
+        let messages: Vec<String> = err.into_iter().map(|err| err.to_string()).collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "Value can not start with '>'",
+                "Value should be a literal, a path, a type or an expression",
+                "Value can not start with '>'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_type_as_single_value() {
+        let src = r#"Vec<u8>"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let single_value = TokensObject::new(token_stream.into()).unwrap();
+
+        let ty: syn::Type = (&single_value).try_into().unwrap();
+        assert_eq!(ty.to_token_stream().to_string(), "Vec < u8 >");
+
+        let src = r#"value: 5, ty: u8"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let ty: syn::Type = params_list
+            .get_named_param("ty")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(ty.to_token_stream().to_string(), "u8");
+
+        let value: u8 = params_list
+            .get_named_param("value")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(value, 5);
+    }
+    #[test]
+    fn test_all_parse_errors_are_reported_at_once() {
+        let src = r#"a: , b 5, c: "ok", d: [1, -"x"], c: 2"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let err = TokensObject::new(token_stream.into()).unwrap_err();
+
+        let messages: Vec<String> = err.into_iter().map(|err| err.to_string()).collect();
+
+        assert_eq!(messages.len(), 5);
+        assert!(messages[0].contains("Value is expected"));
+        assert!(messages[1].contains("Expected ':', '=' or a group of parameters"));
+        assert!(messages[2].contains("Only numbers can be negative"));
+        assert!(messages[3].contains("Duplicate parameter 'c'"));
+    }
+
+    #[test]
+    fn test_all_unknown_params_are_reported_at_once() {
+        let src = r#"nmae: "x", id: 1, tpye: u8"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let err = params_list
+            .check_for_unknown_params(&["name", "id", "type"])
+            .unwrap_err();
+
+        let messages: Vec<String> = err.into_iter().map(|err| err.to_string()).collect();
+
+        assert_eq!(messages.len(), 3);
+        assert_eq!(
+            messages[0],
+            "Unknown parameter 'nmae', did you mean 'name'?"
+        );
+        assert_eq!(
+            messages[1],
+            "Unknown parameter 'tpye', did you mean 'type'?"
+        );
+        assert_eq!(
+            messages[2],
+            "help: supported parameters are: 'name', 'id', 'type'"
+        );
+    }
+
+    #[test]
+    fn test_unknown_param_without_similar_name() {
+        let src = r#"name: "x", something_else: 1"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let err = params_list
+            .check_for_unknown_params(&["name", "id"])
+            .unwrap_err();
+
+        let messages: Vec<String> = err.into_iter().map(|err| err.to_string()).collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "Unknown parameter 'something_else'",
+                "help: supported parameters are: 'name', 'id'"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_error_kinds() {
+        let src = r#"name: 1, id: 300, flag"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let err = params_list.get_named_param("missing").unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::MissingParam {
+                name: "missing".to_string()
+            }
+        );
+
+        let err = params_list.get_positional(0).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::MissingParam {
+                name: "#0".to_string()
+            }
+        );
+
+        let name = params_list.get_named_param("name").unwrap();
+        let err = name.unwrap_as_value().unwrap().as_string().unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::TypeMismatch {
+                expected: "string".to_string(),
+                found: "number".to_string()
+            }
+        );
+
+        let id = params_list.get_named_param("id").unwrap();
+        let err = id
+            .unwrap_as_value()
+            .unwrap()
+            .as_number()
+            .unwrap()
+            .as_u8()
+            .unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Overflow);
+
+        let flag = params_list.get_named_param("flag").unwrap();
+        let err = flag.unwrap_as_value().unwrap().as_string().unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::TypeMismatch {
+                expected: "string".to_string(),
+                found: "no value".to_string()
+            }
+        );
+
+        let err = params_list
+            .check_for_unknown_params(&["name", "id"])
+            .unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::UnknownParam {
+                name: "flag".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_duplicate_param_error_kind() {
+        let src = r#"a: 1, a: 2"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let err = TokensObject::new(token_stream.into()).unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::DuplicateParam {
+                name: "a".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_constraint_error_kinds() {
+        let src = r#"path: "/", regex: "^/$", ttl: 10, cache: false"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let err = params_list
+            .check_exclusive_params(&["path", "regex"])
+            .unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::ConflictingParams {
+                names: vec!["path".to_string(), "regex".to_string()]
+            }
+        );
+        assert_eq!(err.into_iter().count(), 2);
+
+        let err = params_list
+            .check_params_together(&["username", "password", "path"])
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Parameters 'username', 'password', 'path' should be given together. Missing: 'username', 'password'"
+        );
+
+        let err = params_list
+            .check_one_of_required_params(&["id", "name"])
+            .unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::MissingParam {
+                name: "id | name".to_string()
+            }
+        );
+
+        let err = params_list
+            .check_param_requires("ttl", "cache")
+            .unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::MissingParam {
+                name: "cache".to_string()
+            }
+        );
+
+        assert!(params_list.check_param_requires("cache", "ttl").is_ok());
+        assert!(params_list
+            .check_one_of_required_params(&["id", "path"])
+            .is_ok());
+        assert!(params_list.check_params_together(&["path", "ttl"]).is_ok());
+    }
+
+    #[test]
+    fn test_string_literal_names() {
+        let src = r#"headers: { "x-id": "1", accept: "json", "2fa" = true }"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let headers = params_list.get_named_param("headers").unwrap();
+
+        let params = headers.get_map_params().unwrap();
+
+        let names: Vec<&str> = params.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, vec!["x-id", "accept", "2fa"]);
+
+        let value: &str = params[0].1.try_into().unwrap();
+        assert_eq!(value, "1");
+
+        let value: bool = params[2].1.try_into().unwrap();
+        assert!(value);
+
+        assert_eq!(headers.get_key_ident("x-id").unwrap().to_string(), "x_id");
+        assert_eq!(headers.get_key_ident("2fa").unwrap().to_string(), "_2fa");
+    }
+
+    #[test]
+    fn test_string_literal_names_which_are_not_identifier_chars() {
+        let src = r#"fractions: { "½": 1, "a½": 2, "é": 3 }"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let fractions = params_list.get_named_param("fractions").unwrap();
+
+        let names: Vec<&str> = fractions
+            .get_map_params()
+            .unwrap()
+            .iter()
+            .map(|(name, _)| *name)
+            .collect();
+        assert_eq!(names, vec!["½", "a½", "é"]);
+
+        assert_eq!(fractions.get_key_ident("½").unwrap().to_string(), "_");
+        assert_eq!(fractions.get_key_ident("a½").unwrap().to_string(), "a_");
+        assert_eq!(fractions.get_key_ident("é").unwrap().to_string(), "é");
+    }
+
+    #[test]
+    fn test_map_params() {
+        let src = r#"empty: {}, list(a: 1), positional("a", b: 2), value: 5"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let empty = params_list.get_named_param("empty").unwrap();
+        assert!(empty.get_map_params().unwrap().is_empty());
+
+        let list = params_list.get_named_param("list").unwrap();
+        assert_eq!(list.get_map_params().unwrap().len(), 1);
+
+        let positional = params_list.get_named_param("positional").unwrap();
+        let err = positional.get_map_params().unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::InvalidSyntax);
+
+        let value = params_list.get_named_param("value").unwrap();
+        let err = value.get_map_params().unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::TypeMismatch {
+                expected: "object".to_string(),
+                found: "number".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_sequence_items() {
+        let src = r#"array: [1, 2], tuple: (1, 2, 3), single: (1), empty: (), value: 5"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        for (name, len) in [("array", 2), ("tuple", 3), ("single", 1), ("empty", 0)] {
+            let value = params_list.get_named_param(name).unwrap();
+            assert_eq!(value.get_sequence_items().unwrap().len(), len);
+        }
+
+        let array = params_list.get_named_param("array").unwrap();
+        let items = array.get_sequence_items().unwrap();
+        let err = array.check_items_len(items, 3).unwrap_err();
+
+        assert_eq!(err.to_string(), "Value should have 3 items. Found 2");
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::TypeMismatch {
+                expected: "3 items".to_string(),
+                found: "2 items".to_string()
+            }
+        );
+
+        let value = params_list.get_named_param("value").unwrap();
+        assert!(value.get_sequence_items().is_err());
+    }
+
+    #[test]
+    fn test_error_message_can_be_replaced() {
+        let src = r#"name: 1"#;
+
+        let token_stream = proc_macro2::TokenStream::from_str(src).unwrap();
+
+        let params_list = TokensObject::new(token_stream.into()).unwrap();
+
+        let err = params_list
+            .get_named_param("id")
+            .unwrap_err()
+            .with_message("id is required");
+
+        assert_eq!(err.to_string(), "id is required");
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::MissingParam {
+                name: "id".to_string()
+            }
+        );
+
+        let err: syn::Error = err.into();
+        assert_eq!(err.to_string(), "id is required");
+    }
 }
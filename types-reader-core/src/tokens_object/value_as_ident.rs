@@ -5,17 +5,24 @@ use proc_macro2::Ident;
 pub struct ValueAsIdent {
     ident: Ident,
     value: String,
+    path: syn::Path,
 }
 
 impl ValueAsIdent {
     pub fn new(ident: Ident, value: String) -> Self {
-        Self { ident, value }
+        let path = syn::Path::from(ident.clone());
+        Self { ident, value, path }
     }
 
     pub fn get_ident(&self) -> &Ident {
         &self.ident
     }
 
+    /// The ident as a single segment path
+    pub fn get_path(&self) -> &syn::Path {
+        &self.path
+    }
+
     pub fn as_str(&self) -> &str {
         self.value.as_str()
     }
@@ -7,6 +7,8 @@ pub struct TypeName {
     path: Vec<syn::Ident>,
     name: syn::Ident,
     generics: Option<GenericsArrayToken>,
+    borrow_life_time: Option<LifeTimeToken>,
+    where_predicates: Vec<proc_macro2::TokenStream>,
 }
 
 impl TypeName {
@@ -34,19 +36,48 @@ impl TypeName {
     pub fn from_derive_input(ast: &syn::DeriveInput) -> Result<Self, syn::Error> {
         let name = &ast.ident;
 
-        let generics = if ast.generics.lifetimes().count() > 0 {
-            let generics = &ast.generics;
-            let mut tokens_reader = TokensReader::new(quote::quote!(#generics));
-            Some(GenericsArrayToken::from_tokens_reader(&mut tokens_reader)?)
-        } else {
+        let generics = if ast.generics.params.is_empty() {
             None
+        } else {
+            Some(GenericsArrayToken::from_generics(&ast.generics))
         };
 
+        // The bounds of the parameters are moved to the where clause
+        let mut where_predicates = Vec::new();
+
+        for param in &ast.generics.params {
+            match param {
+                syn::GenericParam::Lifetime(param) => {
+                    if !param.bounds.is_empty() {
+                        let life_time = &param.lifetime;
+                        let bounds = &param.bounds;
+                        where_predicates.push(quote::quote!(#life_time: #bounds));
+                    }
+                }
+                syn::GenericParam::Type(param) => {
+                    if !param.bounds.is_empty() {
+                        let ident = &param.ident;
+                        let bounds = &param.bounds;
+                        where_predicates.push(quote::quote!(#ident: #bounds));
+                    }
+                }
+                syn::GenericParam::Const(_) => {}
+            }
+        }
+
+        if let Some(where_clause) = &ast.generics.where_clause {
+            for predicate in &where_clause.predicates {
+                where_predicates.push(quote::quote!(#predicate));
+            }
+        }
+
         Ok(Self {
             reference: None,
             path: Vec::new(),
             name: name.clone(),
             generics,
+            borrow_life_time: None,
+            where_predicates,
         })
     }
 
@@ -72,12 +103,43 @@ impl TypeName {
 
     pub fn get_generic_token_stream_after_impl(&self) -> proc_macro2::TokenStream {
         if let Some(generics) = &self.generics {
-            generics.to_token_stream()
+            generics.to_declaration_token_stream()
         } else {
             quote::quote! {}
         }
     }
 
+    /// Generics after `impl` with the lifetime the struct itself does not have. E.g.: `<'s, T>`
+    fn get_generic_token_stream_after_impl_with(
+        &self,
+        life_time: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let mut declarations = vec![life_time];
+
+        if let Some(generics) = &self.generics {
+            declarations.extend(generics.get_declarations());
+        }
+
+        quote::quote!(<#( #declarations ),*>)
+    }
+
+    pub fn get_type_params(&self) -> Vec<&syn::Ident> {
+        match &self.generics {
+            Some(generics) => generics.get_type_params(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Adds the predicate to the where clause of the generated impls. E.g.: `T: Copy`
+    pub fn add_where_predicate(&mut self, predicate: proc_macro2::TokenStream) {
+        self.where_predicates.push(predicate);
+    }
+
+    /// The bounds of the struct generics and the added predicates
+    pub fn render_where_clause(&self) -> proc_macro2::TokenStream {
+        render_where_clause(self.where_predicates.clone())
+    }
+
     pub fn get_first_life_time(&self) -> Option<&LifeTimeToken> {
         if let Some(generics) = &self.generics {
             generics.get_first_life_time()
@@ -86,6 +148,72 @@ impl TypeName {
         }
     }
 
+    pub fn get_life_times(&self) -> Vec<&LifeTimeToken> {
+        match &self.generics {
+            Some(generics) => generics.get_life_times(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Makes the generated impls borrow the source with the lifetime. E.g.: `#[params(borrow = 'a)]`.
+    /// The lifetime should be one of the struct
+    pub fn set_borrow_life_time(&mut self, life_time: &syn::Lifetime) -> Result<(), syn::Error> {
+        let name = life_time.ident.to_string();
+
+        match self
+            .get_life_times()
+            .into_iter()
+            .find(|itm| itm.as_str() == name)
+        {
+            Some(struct_life_time) => {
+                self.borrow_life_time = Some(struct_life_time.clone());
+                Ok(())
+            }
+            None => Err(syn::Error::new_spanned(
+                life_time,
+                format!("Struct {} has no lifetime {}", self.name, life_time),
+            )),
+        }
+    }
+
+    /// Lifetime the struct borrows the source with: the one set by `set_borrow_life_time` or the first one
+    pub fn get_borrow_life_time(&self) -> Option<&LifeTimeToken> {
+        match &self.borrow_life_time {
+            Some(life_time) => Some(life_time),
+            None => self.get_first_life_time(),
+        }
+    }
+
+    /// The source outlives every other lifetime of the struct: `where 'a: 'b`
+    pub fn render_borrow_where_clause(&self) -> proc_macro2::TokenStream {
+        render_where_clause(self.get_borrow_bounds())
+    }
+
+    fn get_borrow_bounds(&self) -> Vec<proc_macro2::TokenStream> {
+        let mut bounds = Vec::new();
+
+        let borrow_life_time = match self.get_borrow_life_time() {
+            Some(life_time) => life_time,
+            None => return bounds,
+        };
+
+        for life_time in self.get_life_times() {
+            if life_time.as_str() != borrow_life_time.as_str() {
+                let borrow_life_time = borrow_life_time.to_token_stream();
+                let life_time = life_time.to_token_stream();
+                bounds.push(quote::quote!(#borrow_life_time: #life_time));
+            }
+        }
+
+        bounds
+    }
+
+    fn render_impl_where_clause(&self) -> proc_macro2::TokenStream {
+        let mut predicates = self.get_borrow_bounds();
+        predicates.extend(self.where_predicates.iter().cloned());
+        render_where_clause(predicates)
+    }
+
     pub fn get_name_ident(&self) -> &syn::Ident {
         &self.name
     }
@@ -129,8 +257,10 @@ impl TypeName {
 
         let name_ident = self.to_token_stream();
 
+        let where_clause = self.render_where_clause();
+
         quote::quote! {
-            impl #generic_after_impl #name_ident{
+            impl #generic_after_impl #name_ident #where_clause {
                 #content
             }
         }
@@ -145,11 +275,12 @@ impl TypeName {
     ) -> proc_macro2::TokenStream {
         let mut generic_after_impl = self.get_generic_token_stream_after_impl();
         let reference = if from_reference {
-            if let Some(life_time) = self.get_first_life_time() {
+            if let Some(life_time) = self.get_borrow_life_time() {
                 let life_time_token_stream = life_time.to_token_stream();
                 quote::quote!(& #life_time_token_stream)
             } else {
-                generic_after_impl = quote::quote!(<'s>);
+                generic_after_impl =
+                    self.get_generic_token_stream_after_impl_with(quote::quote!('s));
                 quote::quote!(&'s)
             }
         } else {
@@ -168,8 +299,10 @@ impl TypeName {
             }
         };
 
+        let where_clause = self.render_impl_where_clause();
+
         quote::quote! {
-            impl #generic_after_impl TryInto<#name_ident> for #reference #from_struct {
+            impl #generic_after_impl TryInto<#name_ident> for #reference #from_struct #where_clause {
                 #content
             }
         }
@@ -184,11 +317,12 @@ impl TypeName {
     ) -> proc_macro2::TokenStream {
         let mut generic_after_impl = self.get_generic_token_stream_after_impl();
         let reference = if from_reference {
-            if let Some(life_time) = self.get_first_life_time() {
+            if let Some(life_time) = self.get_borrow_life_time() {
                 let life_time_token_stream = life_time.to_token_stream();
                 quote::quote!(& #life_time_token_stream)
             } else {
-                generic_after_impl = quote::quote!(<'s>);
+                generic_after_impl =
+                    self.get_generic_token_stream_after_impl_with(quote::quote!('s));
                 quote::quote!(&'s)
             }
         } else {
@@ -202,19 +336,29 @@ impl TypeName {
         let content = quote::quote! {
             type Error = #error_type;
 
-            fn try_from(value: &'s types_reader::TokensObject) -> Result<Self, Self::Error> {
+            fn try_from(value: #reference #from_struct) -> Result<Self, Self::Error> {
                 #content
             }
         };
 
+        let where_clause = self.render_impl_where_clause();
+
         quote::quote! {
-            impl #generic_after_impl TryFrom<#reference #from_struct> for #name_ident  {
+            impl #generic_after_impl TryFrom<#reference #from_struct> for #name_ident #where_clause {
                 #content
             }
         }
     }
 }
 
+fn render_where_clause(predicates: Vec<proc_macro2::TokenStream>) -> proc_macro2::TokenStream {
+    if predicates.is_empty() {
+        return quote::quote!();
+    }
+
+    quote::quote!(where #( #predicates ),*)
+}
+
 fn read_name_with_generics(
     tokens_reader: &mut TokensReader,
     reference: Option<ReferenceToken>,
@@ -235,6 +379,8 @@ fn read_name_with_generics(
             name,
             generics: None,
             path: vec_of_ident,
+            borrow_life_time: None,
+            where_predicates: Vec::new(),
         });
     }
 
@@ -248,6 +394,8 @@ fn read_name_with_generics(
                 path: vec_of_ident,
                 name,
                 generics: Some(generics),
+                borrow_life_time: None,
+                where_predicates: Vec::new(),
             });
         }
     }
@@ -257,6 +405,8 @@ fn read_name_with_generics(
         path: vec_of_ident,
         name,
         generics: None,
+        borrow_life_time: None,
+        where_predicates: Vec::new(),
     })
 }
 
@@ -331,6 +481,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_type_and_const_generics() {
+        let src = proc_macro2::TokenStream::from_str(
+            r#"pub struct Ranged<T: Copy + PartialOrd, const STRICT: bool = false> where T: Default {
+            pub min: T,
+            pub max: T,
+            }
+        "#,
+        )
+        .unwrap();
+
+        let derive_input = syn::parse2::<syn::DeriveInput>(src).unwrap();
+
+        let struct_name = TypeName::from_derive_input(&derive_input).unwrap();
+
+        assert_eq!(
+            struct_name.to_token_stream().to_string(),
+            "Ranged < T , STRICT >"
+        );
+
+        assert_eq!(
+            struct_name
+                .get_generic_token_stream_after_impl()
+                .to_string(),
+            "< T , const STRICT : bool >"
+        );
+
+        assert_eq!(
+            struct_name.render_where_clause().to_string(),
+            "where T : Copy + PartialOrd , T : Default"
+        );
+
+        let try_from = struct_name.render_try_from_implementation(
+            true,
+            quote::quote!(TokensObject),
+            quote::quote!(syn::Error),
+            || quote::quote!(todo!()),
+        );
+
+        assert!(try_from.to_string().starts_with(
+            "impl < 's , T , const STRICT : bool > TryFrom < & 's TokensObject > for Ranged < T , STRICT > where T : Copy + PartialOrd , T : Default"
+        ));
+    }
+
+    #[test]
+    fn test_borrow_life_time() {
+        let src = proc_macro2::TokenStream::from_str(
+            r#"pub struct IndexAttribute<'ctx, 'src>{
+            pub name: &'src str,
+            pub comment: &'ctx str,
+            }
+        "#,
+        )
+        .unwrap();
+
+        let derive_input = syn::parse2::<syn::DeriveInput>(src).unwrap();
+
+        let mut struct_name = TypeName::from_derive_input(&derive_input).unwrap();
+
+        assert_eq!(struct_name.get_borrow_life_time().unwrap().as_str(), "ctx");
+        assert_eq!(
+            struct_name.render_borrow_where_clause().to_string(),
+            "where 'ctx : 'src"
+        );
+
+        let life_time: syn::Lifetime = syn::parse_str("'src").unwrap();
+        struct_name.set_borrow_life_time(&life_time).unwrap();
+
+        assert_eq!(
+            struct_name.render_borrow_where_clause().to_string(),
+            "where 'src : 'ctx"
+        );
+
+        let life_time: syn::Lifetime = syn::parse_str("'a").unwrap();
+        let err = struct_name.set_borrow_life_time(&life_time).unwrap_err();
+
+        assert_eq!(err.to_string(), "Struct IndexAttribute has no lifetime 'a");
+    }
+
     #[test]
     fn test_from_derive_input_ident() {
         let src = proc_macro2::TokenStream::from_str(
@@ -0,0 +1,276 @@
+use std::fmt::Display;
+
+use proc_macro2::Span;
+use quote::ToTokens;
+
+/// What went wrong. Lets a macro author react to the error without matching its message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The parameter is not supported by the reader
+    UnknownParam { name: String },
+    /// The required parameter is absent. Positional parameters are named by their index: `#0`
+    MissingParam { name: String },
+    /// The value is of another type. E.g.: expected: "string", found: "number"
+    TypeMismatch { expected: String, found: String },
+    /// The number does not fit into the requested type
+    Overflow,
+    /// The parameter appears more than once
+    DuplicateParam { name: String },
+    /// The parameters can not be given together. E.g.: `#[params(exclusive(path, regex))]`
+    ConflictingParams { names: Vec<String> },
+    /// The value is not one of the supported values
+    UnsupportedValue { value: String },
+    /// The value is read, but a validator rejects it. E.g.: `#[param(range = 1..=65535)]`
+    InvalidValue,
+    /// The tokens do not follow the grammar of the attribute
+    InvalidSyntax,
+    /// Any other error. E.g.: a `syn::Error` of a custom reader
+    Other,
+}
+
+/// Error of types-reader with the kind of the problem. Converts into `syn::Error`, so `?` works
+/// in the functions which return `syn::Error`.
+/// If several errors are combined - the kind is the kind of the first one
+#[derive(Debug, Clone)]
+pub struct TypesReaderError {
+    kind: ErrorKind,
+    messages: Vec<ErrorMessage>,
+}
+
+/// One message of the error and the path to the failed value: `as_vec`, `[2]`, `sub_parameter1`
+#[derive(Debug, Clone)]
+struct ErrorMessage {
+    error: syn::Error,
+    path: Vec<String>,
+    is_note: bool,
+}
+
+impl ErrorMessage {
+    fn from_syn_error(error: syn::Error, is_note: bool) -> Vec<Self> {
+        error
+            .into_iter()
+            .map(|error| Self {
+                error,
+                path: Vec::new(),
+                is_note,
+            })
+            .collect()
+    }
+
+    fn get_message(&self) -> String {
+        let message = self.error.to_string();
+
+        if self.path.is_empty() {
+            return message;
+        }
+
+        format!("in `{}`: {}", format_path(&self.path), message)
+    }
+
+    fn to_syn_error(&self) -> syn::Error {
+        if self.path.is_empty() {
+            return self.error.clone();
+        }
+
+        // The tokens of the compile error keep both the start and the end span of the original error
+        syn::Error::new_spanned(self.error.to_compile_error(), self.get_message())
+    }
+}
+
+impl TypesReaderError {
+    pub fn new(kind: ErrorKind, error: syn::Error) -> Self {
+        Self {
+            kind,
+            messages: ErrorMessage::from_syn_error(error, false),
+        }
+    }
+
+    pub fn new_spanned(kind: ErrorKind, tokens: impl ToTokens, message: impl Display) -> Self {
+        Self::new(kind, syn::Error::new_spanned(tokens, message))
+    }
+
+    /// A note which explains the error before it. E.g.: "help: supported parameters are: 'name', 'id'".
+    /// The path of the failed value is not added to the note
+    pub fn new_note(error: syn::Error) -> Self {
+        Self {
+            kind: ErrorKind::Other,
+            messages: ErrorMessage::from_syn_error(error, true),
+        }
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    pub fn span(&self) -> Span {
+        self.messages[0].error.span()
+    }
+
+    /// Replaces the message. The error keeps the kind and points at the same token
+    pub fn with_message(self, message: impl Display) -> Self {
+        let error = syn::Error::new(self.span(), message);
+        Self::new(self.kind, error)
+    }
+
+    /// Adds a segment to the path of the failed value: a field name or an index like `[2]`.
+    /// Segments are added from the inner value to the outer one, so the message becomes:
+    /// "in `as_vec[2].sub_parameter1`: Type should be a string"
+    pub fn with_context(mut self, segment: &str) -> Self {
+        for message in self.messages.iter_mut() {
+            if !message.is_note {
+                message.path.insert(0, segment.to_string());
+            }
+        }
+
+        self
+    }
+
+    pub fn combine(&mut self, other: impl Into<TypesReaderError>) {
+        self.messages.extend(other.into().messages);
+    }
+
+    pub fn to_compile_error(&self) -> proc_macro2::TokenStream {
+        self.to_syn_error().to_compile_error()
+    }
+
+    pub fn into_syn_error(self) -> syn::Error {
+        self.to_syn_error()
+    }
+
+    fn to_syn_error(&self) -> syn::Error {
+        let mut result: Option<syn::Error> = None;
+
+        for message in &self.messages {
+            let error = message.to_syn_error();
+
+            match &mut result {
+                Some(result) => result.combine(error),
+                None => result = Some(error),
+            }
+        }
+
+        result.unwrap()
+    }
+}
+
+impl Display for TypesReaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.messages[0].get_message().as_str())
+    }
+}
+
+impl std::error::Error for TypesReaderError {}
+
+/// `syn::Error` has no kind and no path, so it becomes `ErrorKind::Other`.
+/// The readers return `TypesReaderError`, so the kind and the path of their errors are not lost this way
+impl From<syn::Error> for TypesReaderError {
+    fn from(error: syn::Error) -> Self {
+        Self {
+            kind: ErrorKind::Other,
+            messages: ErrorMessage::from_syn_error(error, false),
+        }
+    }
+}
+
+impl From<TypesReaderError> for syn::Error {
+    fn from(error: TypesReaderError) -> Self {
+        error.into_syn_error()
+    }
+}
+
+impl IntoIterator for TypesReaderError {
+    type Item = syn::Error;
+    type IntoIter = <syn::Error as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_syn_error().into_iter()
+    }
+}
+
+fn format_path(path: &[String]) -> String {
+    let mut result = String::new();
+
+    for segment in path {
+        if !result.is_empty() && !segment.starts_with('[') {
+            result.push('.');
+        }
+
+        result.push_str(segment);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::Span;
+
+    use super::{ErrorKind, TypesReaderError};
+
+    fn messages(err: TypesReaderError) -> Vec<String> {
+        err.into_iter().map(|err| err.to_string()).collect()
+    }
+
+    #[test]
+    fn test_path_is_added_to_each_message_but_the_note() {
+        let mut err = TypesReaderError::new(
+            ErrorKind::InvalidValue,
+            syn::Error::new(Span::call_site(), "Value is wrong"),
+        );
+        err.combine(TypesReaderError::new_note(syn::Error::new(
+            Span::call_site(),
+            "help: use another value",
+        )));
+
+        let err = err.with_context("[2]").with_context("items");
+
+        assert_eq!(err.to_string(), "in `items[2]`: Value is wrong");
+        assert_eq!(
+            messages(err),
+            vec!["in `items[2]`: Value is wrong", "help: use another value"]
+        );
+    }
+
+    #[test]
+    fn test_message_which_looks_like_a_path() {
+        let err = TypesReaderError::new(
+            ErrorKind::InvalidValue,
+            syn::Error::new(Span::call_site(), "in `a`: b"),
+        );
+
+        let err = err.with_context("field");
+
+        assert_eq!(err.to_string(), "in `field`: in `a`: b");
+    }
+
+    #[test]
+    fn test_path_is_in_message_of_syn_error() {
+        let err = TypesReaderError::new(
+            ErrorKind::InvalidValue,
+            syn::Error::new(Span::call_site(), "Value is wrong"),
+        )
+        .with_context("inner")
+        .with_context("outer");
+
+        let err: syn::Error = err.into();
+        assert_eq!(err.to_string(), "in `outer.inner`: Value is wrong");
+    }
+
+    #[test]
+    fn test_syn_error_is_other_kind() {
+        let err = TypesReaderError::new(
+            ErrorKind::MissingParam {
+                name: "id".to_string(),
+            },
+            syn::Error::new(Span::call_site(), "Field 'id' is required"),
+        );
+
+        let _: syn::Error = err.into();
+
+        // The same text does not make an unrelated error the missing parameter
+        let err =
+            TypesReaderError::from(syn::Error::new(Span::call_site(), "Field 'id' is required"));
+        assert_eq!(err.kind(), &ErrorKind::Other);
+        assert_eq!(err.to_string(), "Field 'id' is required");
+    }
+}
@@ -1,3 +1,9 @@
+use syn::ext::IdentExt;
+
+use crate::{
+    ErrorKind, ObjectItem, OptionalObjectValue, OrderedMap, TokensObject, TypesReaderError,
+};
+
 pub fn to_snake_case(name: &str) -> String {
     let mut result = String::new();
 
@@ -14,3 +20,313 @@ pub fn to_snake_case(name: &str) -> String {
 
     result
 }
+
+/// snake_case into PascalCase: `max_body_size` - `MaxBodySize`
+pub fn to_pascal_case(name: &str) -> String {
+    let mut result = String::new();
+    let mut capitalize = true;
+
+    for c in name.chars() {
+        if c == '_' {
+            capitalize = true;
+        } else if capitalize {
+            result.push(c.to_ascii_uppercase());
+            capitalize = false;
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+/// snake_case into camelCase: `max_body_size` - `maxBodySize`
+pub fn to_camel_case(name: &str) -> String {
+    let pascal_case = to_pascal_case(name);
+    let mut chars = pascal_case.chars();
+
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => pascal_case,
+    }
+}
+
+/// snake_case into kebab-case: `max_body_size` - `max-body-size`
+pub fn to_kebab_case(name: &str) -> String {
+    name.replace('_', "-")
+}
+
+/// The cases `rename_all` supports. The same ones serde supports
+pub const RENAME_ALL_CASES: &[&str] = &[
+    "lowercase",
+    "UPPERCASE",
+    "PascalCase",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+];
+
+/// Converts the snake_case name of the field into one of the `RENAME_ALL_CASES`.
+/// Returns None if the case is not supported
+pub fn convert_case(name: &str, case: &str) -> Option<String> {
+    let result = match case {
+        "lowercase" | "snake_case" => name.to_string(),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => name.to_ascii_uppercase(),
+        "PascalCase" => to_pascal_case(name),
+        "camelCase" => to_camel_case(name),
+        "kebab-case" => to_kebab_case(name),
+        "SCREAMING-KEBAB-CASE" => to_kebab_case(name).to_ascii_uppercase(),
+        _ => return None,
+    };
+
+    Some(result)
+}
+
+/// Edit distance between two names: insertions, deletions, substitutions and swaps of two adjacent letters.
+/// Letter case is ignored
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().flat_map(|c| c.to_lowercase()).collect();
+    let b: Vec<char> = b.chars().flat_map(|c| c.to_lowercase()).collect();
+
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution_cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+
+            let mut distance = (distances[i - 1][j - 1] + substitution_cost)
+                .min(distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+/// Finds the closest name to suggest instead of the misspelled one.
+/// Returns None if no name is close enough to be a typo
+pub fn find_similar_name<'s>(name: &str, candidates: &[&'s str]) -> Option<&'s str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    candidates
+        .iter()
+        .map(|candidate| (*candidate, edit_distance(name, candidate)))
+        .filter(|(_, distance)| *distance <= max_distance)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| candidate)
+}
+
+/// Builds the error message for the unknown name with the "did you mean" suggestion if there is one.
+/// E.g.: Unknown parameter 'descripton', did you mean 'description'?
+pub fn format_unknown_name_message(message: &str, name: &str, candidates: &[&str]) -> String {
+    match find_similar_name(name, candidates) {
+        Some(similar_name) => format!("{} '{}', did you mean '{}'?", message, name, similar_name),
+        None => format!("{} '{}'", message, name),
+    }
+}
+
+/// Names for the messages: 'path', 'regex'
+pub fn format_names(names: &[&str]) -> String {
+    let names: Vec<String> = names.iter().map(|name| format!("'{}'", name)).collect();
+    names.join(", ")
+}
+
+/// Lists all the supported names. Is reported as a separate error next to the unknown name
+pub fn format_supported_names_note(names_kind: &str, candidates: &[&str]) -> String {
+    format!(
+        "help: supported {} are: {}",
+        names_kind,
+        format_names(candidates)
+    )
+}
+
+/// Adds the field to the path of the error. Used by the generated code while reading the field.
+/// The field which is missing is already named by the error, so the path is not added to it
+pub fn in_field<T, TError: Into<TypesReaderError>>(
+    field: &str,
+    result: Result<T, TError>,
+) -> Result<T, TypesReaderError> {
+    result.map_err(|err| {
+        let err = err.into();
+        match err.kind() {
+            ErrorKind::MissingParam { name } if name == field => err,
+            _ => err.with_context(field),
+        }
+    })
+}
+
+/// Adds the index of the array item to the path of the error. Used by the generated code while reading Vec items
+pub fn in_item<T, TError: Into<TypesReaderError>>(
+    index: usize,
+    result: Result<T, TError>,
+) -> Result<T, TypesReaderError> {
+    result.map_err(|err| err.into().with_context(format!("[{}]", index).as_str()))
+}
+
+/// Argument of the `#[param(parse_with = path)]` function: `&TokensObject` or `&OptionalObjectValue`
+pub trait ParseWithArg<'s>: Sized {
+    fn from_tokens_object(value: &'s TokensObject) -> Result<Self, TypesReaderError>;
+}
+
+impl<'s> ParseWithArg<'s> for &'s TokensObject {
+    fn from_tokens_object(value: &'s TokensObject) -> Result<Self, TypesReaderError> {
+        Ok(value)
+    }
+}
+
+impl<'s> ParseWithArg<'s> for &'s OptionalObjectValue {
+    fn from_tokens_object(value: &'s TokensObject) -> Result<Self, TypesReaderError> {
+        value.unwrap_as_value()
+    }
+}
+
+/// Reads the value with the `#[param(parse_with = path)]` function. Used by the generated code
+pub fn parse_with<'s, TArg: ParseWithArg<'s>, T, TError: Into<TypesReaderError>>(
+    value: &'s TokensObject,
+    parse_fn: impl FnOnce(TArg) -> Result<T, TError>,
+) -> Result<T, TypesReaderError> {
+    parse_fn(TArg::from_tokens_object(value)?).map_err(Into::into)
+}
+
+/// Type of the `#[param(rest)]` field, which collects the named parameters no other field reads:
+/// `Vec<(&syn::Ident, &TokensObject)>` or `OrderedMap` by the name of the parameter
+pub trait FromRestParams<'s>: Sized {
+    fn from_rest_params(params: Vec<(&'s str, &'s ObjectItem)>) -> Self;
+}
+
+impl<'s> FromRestParams<'s> for Vec<(&'s syn::Ident, &'s TokensObject)> {
+    fn from_rest_params(params: Vec<(&'s str, &'s ObjectItem)>) -> Self {
+        params
+            .into_iter()
+            .map(|(_, item)| (&item.ident, &item.value))
+            .collect()
+    }
+}
+
+impl<'s> FromRestParams<'s> for OrderedMap<&'s str, &'s TokensObject> {
+    fn from_rest_params(params: Vec<(&'s str, &'s ObjectItem)>) -> Self {
+        params
+            .into_iter()
+            .map(|(name, item)| (name, &item.value))
+            .collect()
+    }
+}
+
+impl<'s> FromRestParams<'s> for OrderedMap<String, &'s TokensObject> {
+    fn from_rest_params(params: Vec<(&'s str, &'s ObjectItem)>) -> Self {
+        params
+            .into_iter()
+            .map(|(name, item)| (name.to_string(), &item.value))
+            .collect()
+    }
+}
+
+/// Reads the `#[param(rest)]` field. Used by the generated code
+pub fn read_rest_params<'s, T: FromRestParams<'s>>(
+    tokens_object: &'s TokensObject,
+    used_parameters: &[&str],
+) -> T {
+    T::from_rest_params(tokens_object.get_unknown_params(used_parameters))
+}
+
+/// Tokens which make the compiler warn about every deprecated parameter given in the attribute.
+/// `deprecated` - the deprecated names of the parameter and the note. Used by the generated code
+pub fn render_deprecation_warnings(
+    tokens_object: &TokensObject,
+    deprecated: &[(&[&str], &str)],
+) -> proc_macro2::TokenStream {
+    let mut result = proc_macro2::TokenStream::new();
+
+    for (names, note) in deprecated {
+        for name in names.iter() {
+            if let (Some(key), Some(key_tokens)) = (
+                tokens_object.get_key_ident(name),
+                tokens_object.get_key_tokens(name),
+            ) {
+                result.extend(render_deprecation_warning(key, key_tokens, note));
+            }
+        }
+    }
+
+    result
+}
+
+// The usual trick: a use of the `#[deprecated]` const spanned with the key
+fn render_deprecation_warning(
+    key: &syn::Ident,
+    key_tokens: &proc_macro2::TokenStream,
+    note: &str,
+) -> proc_macro2::TokenStream {
+    let name = key.unraw().to_string();
+    let span = get_joined_span(key_tokens).unwrap_or_else(|| key.span());
+
+    // A keyword can not be the name of the const: `type` becomes `type_`
+    let name = match syn::parse_str::<syn::Ident>(&name) {
+        Ok(_) => syn::Ident::new(&name, span),
+        Err(_) => syn::Ident::new(&format!("{}_", name), span),
+    };
+
+    quote::quote! {
+        const _: () = {
+            #[deprecated(note = #note)]
+            #[allow(non_upper_case_globals)]
+            const #name: () = ();
+            #name
+        };
+    }
+}
+
+// The span from the first to the last token: the whole `content-type`.
+// Spans can be joined only by a nightly compiler, so it is `None` on stable
+fn get_joined_span(tokens: &proc_macro2::TokenStream) -> Option<proc_macro2::Span> {
+    let mut tokens = tokens.clone().into_iter();
+    let first = tokens.next()?.span();
+
+    match tokens.last() {
+        Some(last) => first.join(last.span()),
+        None => Some(first),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_convert_case() {
+        let cases: Vec<String> = super::RENAME_ALL_CASES
+            .iter()
+            .map(|case| super::convert_case("max_body_size", case).unwrap())
+            .collect();
+
+        assert_eq!(
+            cases,
+            vec![
+                "max_body_size",
+                "MAX_BODY_SIZE",
+                "MaxBodySize",
+                "maxBodySize",
+                "max_body_size",
+                "MAX_BODY_SIZE",
+                "max-body-size",
+                "MAX-BODY-SIZE"
+            ]
+        );
+
+        assert!(super::convert_case("name", "Title Case").is_none());
+    }
+}
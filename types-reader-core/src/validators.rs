@@ -0,0 +1,188 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fmt::Debug,
+    fmt::Display,
+    ops::RangeBounds,
+};
+
+use crate::{ErrorKind, OrderedMap, TokensObject, TypesReaderError};
+
+/// Length of the value for `non_empty` and `len`
+pub trait HasLen {
+    fn get_len(&self) -> usize;
+}
+
+impl HasLen for str {
+    fn get_len(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl HasLen for String {
+    fn get_len(&self) -> usize {
+        self.as_str().get_len()
+    }
+}
+
+impl<T> HasLen for [T] {
+    fn get_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> HasLen for Vec<T> {
+    fn get_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V> HasLen for HashMap<K, V> {
+    fn get_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V> HasLen for BTreeMap<K, V> {
+    fn get_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> HasLen for HashSet<T> {
+    fn get_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> HasLen for BTreeSet<T> {
+    fn get_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V> HasLen for OrderedMap<K, V> {
+    fn get_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T: HasLen + ?Sized> HasLen for &T {
+    fn get_len(&self) -> usize {
+        (**self).get_len()
+    }
+}
+
+// The checks behind the `#[param(...)]` validators of the derive. Each returns the message of the problem,
+// `validation_error` points it to the value in the attribute
+
+/// `#[param(range = 1..=65535)]`
+pub fn check_range<T: PartialOrd, R: RangeBounds<T> + Debug>(
+    value: &T,
+    range: R,
+) -> Result<(), String> {
+    if range.contains(value) {
+        return Ok(());
+    }
+
+    Err(format!("Value should be in the range {:?}", range))
+}
+
+/// `#[param(non_empty)]`
+pub fn check_non_empty<T: HasLen + ?Sized>(value: &T) -> Result<(), String> {
+    if value.get_len() > 0 {
+        return Ok(());
+    }
+
+    Err("Value should not be empty".to_string())
+}
+
+/// `#[param(len = 1..=64)]`. Strings are measured in chars
+pub fn check_len<T: HasLen + ?Sized, R: RangeBounds<usize> + Debug>(
+    value: &T,
+    range: R,
+) -> Result<(), String> {
+    let len = value.get_len();
+
+    if range.contains(&len) {
+        return Ok(());
+    }
+
+    Err(format!(
+        "Length of the value should be in the range {:?}. Length is {}",
+        range, len
+    ))
+}
+
+/// `#[param(one_of = ["GET", "POST"])]`
+pub fn check_one_of<T: PartialEq<U>, U: Debug>(value: &T, items: &[U]) -> Result<(), String> {
+    if items.iter().any(|item| value == item) {
+        return Ok(());
+    }
+
+    let items: Vec<String> = items.iter().map(|item| format!("{:?}", item)).collect();
+
+    Err(format!("Value should be one of: {}", items.join(", ")))
+}
+
+/// Error of a validator, spanned to the value it rejects
+pub fn validation_error(value: &TokensObject, message: impl Display) -> TypesReaderError {
+    TypesReaderError::new(
+        ErrorKind::InvalidValue,
+        value.throw_error_at_value_token(message.to_string().as_str()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_check_range() {
+        assert!(check_range(&80u16, 1..=65535).is_ok());
+        assert!(check_range(&1.5f64, 0.0..1.0).is_err());
+
+        assert_eq!(
+            check_range(&0u16, 1..=65535).unwrap_err(),
+            "Value should be in the range 1..=65535"
+        );
+    }
+
+    #[test]
+    fn test_check_len() {
+        assert!(check_non_empty("a").is_ok());
+        assert!(check_non_empty(&Vec::<u8>::new()).is_err());
+
+        assert!(check_len(&"añb", 3..=3).is_ok());
+
+        assert_eq!(
+            check_len(&vec![1, 2, 3], ..3).unwrap_err(),
+            "Length of the value should be in the range ..3. Length is 3"
+        );
+    }
+
+    #[test]
+    fn test_validation_error() {
+        let token_stream = proc_macro2::TokenStream::from_str("port: 0").unwrap();
+        let tokens_object = TokensObject::new(token_stream.into()).unwrap();
+        let port = tokens_object.get_named_param("port").unwrap();
+
+        let err = validation_error(port, check_range(&0u16, 1..).unwrap_err());
+
+        assert_eq!(err.kind(), &ErrorKind::InvalidValue);
+        assert_eq!(err.to_string(), "Value should be in the range 1..");
+    }
+
+    #[test]
+    fn test_check_one_of() {
+        assert!(check_one_of(&"GET", &["GET", "POST"]).is_ok());
+        assert!(check_one_of(&String::from("POST"), &["GET", "POST"]).is_ok());
+        assert!(check_one_of(&2u8, &[1, 2]).is_ok());
+
+        assert_eq!(
+            check_one_of(&"PUT", &["GET", "POST"]).unwrap_err(),
+            "Value should be one of: \"GET\", \"POST\""
+        );
+    }
+}
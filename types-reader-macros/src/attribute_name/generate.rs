@@ -19,9 +19,11 @@ pub fn generate(
 
     let impl_generics = structure_schema.name.get_generic_token_stream_after_impl();
 
+    let where_clause = structure_schema.name.render_where_clause();
+
     Ok(quote::quote! {
         #ast
-        impl #impl_generics types_reader::MacrosAttribute for #ident_with_generics {
+        impl #impl_generics types_reader::MacrosAttribute for #ident_with_generics #where_clause {
             const NAME:&'static str = #attribute_name;
         }
     }
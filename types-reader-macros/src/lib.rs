@@ -5,7 +5,15 @@ use proc_macro::TokenStream;
 
 #[proc_macro_derive(
     MacrosParameters,
-    attributes(any_value_as_string, allow_ident, default, has_attribute)
+    attributes(
+        any_value_as_string,
+        allow_ident,
+        default,
+        positional,
+        has_attribute,
+        param,
+        params
+    )
 )]
 pub fn macros_parameters(input: TokenStream) -> TokenStream {
     match crate::macros_parameters::generate(input) {
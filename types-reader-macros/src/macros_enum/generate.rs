@@ -10,7 +10,7 @@ pub fn generate(input: TokenStream) -> Result<TokenStream, syn::Error> {
 
     let mut try_from_str_cases = Vec::new();
 
-    let mut supported_cases = String::new();
+    let mut supported_cases = Vec::new();
 
     let mut enum_cases_with_model = Vec::new();
 
@@ -32,19 +32,13 @@ pub fn generate(input: TokenStream) -> Result<TokenStream, syn::Error> {
         let case_as_str = super::utils::get_enum_str_value(&src)?;
         let case_as_str = case_as_str.as_str();
 
-        if try_from_str_cases.len() > 0 {
-            supported_cases.push_str(",");
-        }
-
-        supported_cases.push('\'');
-        supported_cases.push_str(case_as_str);
+        supported_cases.push(case_as_str.to_string());
 
         try_from_str_cases.push(quote::quote! {
             if value == #case_as_str{
                 return Some(Self::#case_ident);
             }
         });
-        supported_cases.push('\'');
 
         as_str_cases.push(quote::quote!(#name_ident::#case_ident => #case_as_str,));
     }
@@ -96,14 +90,21 @@ pub fn generate(input: TokenStream) -> Result<TokenStream, syn::Error> {
     };
 
     let try_into_error = quote::quote! {
-        let err = self.throw_error(
-            format!(
-                "Unsupported value: {}. Supported values are: {}",
-                value, #supported_cases
-            )
-            .as_str(),
-           );
-           Err(err)
+        let supported_cases: &[&str] = &[#( #supported_cases ),*];
+
+        let mut err = types_reader::TypesReaderError::new(
+            types_reader::ErrorKind::UnsupportedValue {
+                value: value.to_string(),
+            },
+            self.throw_error(
+                types_reader::utils::format_unknown_name_message("Unsupported value", value, supported_cases)
+                    .as_str(),
+            ),
+        );
+        err.combine(types_reader::TypesReaderError::new_note(self.throw_error(
+            types_reader::utils::format_supported_names_note("values", supported_cases).as_str(),
+        )));
+        Err(err)
     };
 
     let as_str_impl = if has_vec_case || as_str_cases.len() == 0 {
@@ -132,7 +133,7 @@ pub fn generate(input: TokenStream) -> Result<TokenStream, syn::Error> {
 
 
         impl<'s> TryInto<#name_ident> for &'s types_reader::ObjectValue{
-            type Error = syn::Error;
+            type Error = types_reader::TypesReaderError;
             fn try_into(self) -> Result<#name_ident, Self::Error> {
                 let value = self.as_string()?.as_str();
 
@@ -146,7 +147,7 @@ pub fn generate(input: TokenStream) -> Result<TokenStream, syn::Error> {
         }
 
         impl<'s> TryInto<#name_ident> for &'s types_reader::OptionalObjectValue{
-            type Error = syn::Error;
+            type Error = types_reader::TypesReaderError;
             fn try_into(self) -> Result<#name_ident, Self::Error> {
                 let value = self.as_string()?.as_str();
 
@@ -160,16 +161,16 @@ pub fn generate(input: TokenStream) -> Result<TokenStream, syn::Error> {
         }
 
         impl<'s> TryInto<#name_ident> for &'s types_reader::TokensObject{
-            type Error = syn::Error;
+            type Error = types_reader::TypesReaderError;
             fn try_into(self) -> Result<#name_ident, Self::Error> {
                 #( #generated_model_cases )*
                 let value = self.unwrap_as_value()?;
-                Ok(value.try_into()?)
+                value.try_into()
             }
         }
 
         impl<'s> TryInto<#name_ident> for &'s dyn types_reader::AnyValueAsStr<'s> {
-            type Error = syn::Error;
+            type Error = types_reader::TypesReaderError;
 
             fn try_into(self) -> Result<#name_ident, Self::Error> {
                 let value = self.as_str()?;
@@ -0,0 +1,104 @@
+use types_reader_core::{
+    ObjectValue, PropertyType, StructProperty, TokensObject, TypesReaderError,
+};
+
+/// Expression of the value the property gets if the parameter is absent.
+/// `#[param(default)]` - `Default::default()`
+/// `#[param(default = 8080)]` - the value. Literals are checked against the type of the property right here
+/// `#[param(default_fn = path)]` - the result of the function call
+pub fn generate_default_value(
+    property: &StructProperty,
+    param_attr: &TokensObject,
+) -> Result<Option<proc_macro2::TokenStream>, syn::Error> {
+    let default = param_attr.try_get_named_param("default");
+    let default_fn = param_attr.try_get_named_param("default_fn");
+
+    if default.is_none() && default_fn.is_none() {
+        return Ok(None);
+    }
+
+    if property.ty.is_option() {
+        return property.throw_error("'default' can not be applied to Option property");
+    }
+
+    let result = match (default, default_fn) {
+        (Some(_), Some(default_fn)) => {
+            return Err(default_fn.throw_error_at_param_token(
+                "'default' and 'default_fn' can not be applied to the same property",
+            ));
+        }
+        (Some(default), None) => {
+            if default.has_no_value() {
+                quote::quote!(Default::default())
+            } else {
+                generate_value(&property.ty, default.unwrap_as_value()?.unwrap_value()?)?
+            }
+        }
+        (None, Some(default_fn)) => {
+            let path = default_fn.unwrap_as_value()?.unwrap_value()?.get_path()?;
+            quote::quote!(#path())
+        }
+        (None, None) => unreachable!(),
+    };
+
+    Ok(Some(result))
+}
+
+fn generate_value(
+    ty: &PropertyType,
+    value: &ObjectValue,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    // Not a literal: the expression is type-checked by the compiler. E.g.: Method::Get
+    if let ObjectValue::Ident(_) | ObjectValue::Path(_) | ObjectValue::RawTokens(_) = value {
+        return Ok(value.to_token_stream());
+    }
+
+    let result = match ty {
+        PropertyType::U8 => to_tokens::<u8>(value)?,
+        PropertyType::I8 => to_tokens::<i8>(value)?,
+        PropertyType::U16 => to_tokens::<u16>(value)?,
+        PropertyType::I16 => to_tokens::<i16>(value)?,
+        PropertyType::U32 => to_tokens::<u32>(value)?,
+        PropertyType::I32 => to_tokens::<i32>(value)?,
+        PropertyType::U64 => to_tokens::<u64>(value)?,
+        PropertyType::I64 => to_tokens::<i64>(value)?,
+        PropertyType::U128 => to_tokens::<u128>(value)?,
+        PropertyType::I128 => to_tokens::<i128>(value)?,
+        PropertyType::USize => to_tokens::<usize>(value)?,
+        PropertyType::ISize => to_tokens::<isize>(value)?,
+        PropertyType::F32 => to_tokens::<f32>(value)?,
+        PropertyType::F64 => to_tokens::<f64>(value)?,
+        PropertyType::Bool => {
+            let value = value.as_bool()?.get_value();
+            quote::quote!(#value)
+        }
+        PropertyType::String => {
+            let value = value.as_string()?.as_str();
+            quote::quote!(String::from(#value))
+        }
+        PropertyType::RefTo { ty, .. } if ty.as_str().as_str() == "str" => {
+            let value = value.as_string()?.as_str();
+            quote::quote!(#value)
+        }
+        PropertyType::Struct(name, _) if name == "char" => {
+            let value = value.as_char()?.get_value();
+            quote::quote!(#value)
+        }
+        _ => {
+            let value = value.to_token_stream();
+            quote::quote!(#value.into())
+        }
+    };
+
+    Ok(result)
+}
+
+fn to_tokens<'s, T: quote::ToTokens>(
+    value: &'s ObjectValue,
+) -> Result<proc_macro2::TokenStream, syn::Error>
+where
+    &'s ObjectValue: TryInto<T, Error = TypesReaderError>,
+{
+    let value: T = value.try_into()?;
+    Ok(quote::quote!(#value))
+}
@@ -1,5 +1,5 @@
 use proc_macro::TokenStream;
-use types_reader_core::{PropertyType, StructProperty, StructureSchema};
+use types_reader_core::{PropertyType, StructProperty, StructureSchema, TokensObject};
 
 pub const OBJECT_VALUE_TYPE_NAME: &str = "ObjectValue";
 pub const TOKENS_OBJECT_TYPE_NAME: &str = "TokensObject";
@@ -9,21 +9,109 @@ pub const ANY_VALUE_TYPE_NAME: &str = "AnyValue";
 
 pub fn generate(input: TokenStream) -> Result<TokenStream, syn::Error> {
     let ast: syn::DeriveInput = syn::parse(input).unwrap();
-    let structure_schema = StructureSchema::new(&ast)?;
+    let mut structure_schema = StructureSchema::new(&ast)?;
+
+    let params_attr = super::utils::get_params_attr(&structure_schema)?;
+
+    if let Some(life_time) = super::utils::get_borrow_life_time(params_attr)? {
+        structure_schema.name.set_borrow_life_time(&life_time)?;
+    }
+
+    // Every type parameter is read from the attribute the way the field of the type is
+    let type_params: Vec<syn::Ident> = structure_schema
+        .name
+        .get_type_params()
+        .into_iter()
+        .cloned()
+        .collect();
+
+    for type_param in type_params {
+        structure_schema.name.add_where_predicate(quote::quote!(
+            for<'x> &'x types_reader::TokensObject: TryInto<#type_param, Error = types_reader::TypesReaderError>
+        ));
+    }
+
     generate_content(&structure_schema).map(Into::into)
 }
 
 pub fn generate_content(
     structure_schema: &StructureSchema,
 ) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let mut prop_idents = Vec::new();
+    let mut prop_values = Vec::new();
+    let mut prop_types = Vec::new();
+    let mut prop_paths = Vec::new();
     let mut reading_props = Vec::new();
+    let mut positional_slots: Vec<Option<String>> = Vec::new();
+    let mut named_fields = Vec::new();
+    let mut deprecated_params = Vec::new();
+    let mut param_aliases: Vec<(String, Vec<String>)> = Vec::new();
+    let mut requires = Vec::new();
+    let mut flattened_types = Vec::new();
+    let mut has_rest = false;
+
+    let mut constraints = Vec::new();
+
+    let params_attr = super::utils::get_params_attr(structure_schema)?;
+    let rename_all = super::utils::get_rename_all(params_attr)?;
 
     for property in structure_schema.get_all() {
         let prop_ident = property.get_field_name_ident();
 
-        let prop_name = prop_ident.to_string();
+        let param_attr = super::utils::get_param_attr(property)?;
 
-        reading_props.push(quote::quote!(#prop_ident: ));
+        let prop_name = super::utils::get_param_name(property, param_attr, rename_all)?;
+
+        let aliases = super::utils::get_aliases(param_attr)?;
+
+        // The parameter with aliases is read by the name it is given with
+        let name_expr = if aliases.is_empty() {
+            quote::quote!(#prop_name)
+        } else {
+            quote::quote!(__param_name)
+        };
+
+        if let Some(note) = super::utils::get_deprecated_note(param_attr)? {
+            // The aliases are the old names. Without aliases the parameter itself is deprecated
+            let names = if aliases.is_empty() {
+                vec![prop_name.clone()]
+            } else {
+                aliases.clone()
+            };
+
+            deprecated_params.push((names, note));
+        }
+
+        prop_types.push(&property.field.ty);
+        prop_paths.push(Some(prop_name.clone()));
+        prop_values.push(quote::format_ident!("__prop_value_{}", prop_idents.len()));
+        prop_idents.push(prop_ident);
+
+        if param_attr.is_some_and(|attr| attr.has_param("rest")) {
+            check_rest_property(property, param_attr.unwrap(), has_rest)?;
+            has_rest = true;
+
+            *prop_paths.last_mut().unwrap() = None;
+
+            reading_props.push(quote::quote!(types_reader::utils::read_rest_params(
+                value,
+                &Self::get_param_names()
+            )));
+            continue;
+        }
+
+        if param_attr.is_some_and(|attr| attr.has_param("flatten")) {
+            check_flattened_property(property, param_attr.unwrap())?;
+
+            // The fields of the flattened struct are at the same level, so the error paths do not name it
+            *prop_paths.last_mut().unwrap() = None;
+
+            let ty = &property.field.ty;
+            reading_props.push(quote::quote!(<#ty>::read_fields(value)?));
+            constraints.push(quote::quote!(<#ty>::check_constraints(tokens_object)));
+            flattened_types.push(ty);
+            continue;
+        }
 
         //todo!("Temporary reading ident and is_any_value_as_string is the same");
         let ident_is_allowed = super::utils::is_ident_allowed(property)
@@ -31,14 +119,53 @@ pub fn generate_content(
 
         let has_attribute = property.attrs.has_attr("has_attribute");
 
+        let default_value = match param_attr {
+            Some(param_attr) => super::default_value::generate_default_value(property, param_attr)?,
+            None => None,
+        };
+
+        let parse_fn = super::parse_with::get_parse_fn(param_attr)?;
+
+        // With the default value the parameter is read as optional
+        let reading_ty = if default_value.is_some() {
+            PropertyType::OptionOf(Box::new(property.ty.clone()))
+        } else {
+            property.ty.clone()
+        };
+
         if has_attribute {
+            if default_value.is_some() {
+                return property.throw_error(
+                    "'has_attribute' and 'default' can not be applied to the same property",
+                );
+            }
+
+            if parse_fn.is_some() {
+                return property.throw_error(
+                    "'has_attribute' and 'parse_with' can not be applied to the same property",
+                );
+            }
+
+            if super::validators::has_validators(param_attr) {
+                return property
+                    .throw_error("Validators can not be applied to 'has_attribute' property");
+            }
+
             if !property.ty.is_boolean() {
                 return property
                     .throw_error("'has_attribute' can be applied only to bool property");
             } else {
-                reading_props.push(quote::quote! {
-                    value.has_param(#prop_name),
-                });
+                let reading = quote::quote! {
+                    value.has_param(#name_expr)
+                };
+                reading_props.push(read_with_aliases(&prop_name, &aliases, reading));
+                named_fields.push(prop_name.clone());
+                named_fields.extend(aliases.iter().cloned());
+                param_aliases.push((prop_name.clone(), aliases));
+            }
+
+            if let Some((value, names)) = super::utils::get_requires(param_attr)? {
+                requires.push((prop_name, value, names));
             }
 
             continue;
@@ -46,54 +173,305 @@ pub fn generate_content(
 
         let is_default = super::utils::is_default(property);
 
-        if property.ty.is_vec() {
-            reading_props.push(generate_reading_from_vec(&prop_name));
+        if let Some(index) = super::utils::get_positional_index(property)? {
+            if param_attr.is_some_and(|attr| {
+                attr.has_param("alias")
+                    || attr.has_param("deprecated")
+                    || attr.has_param("requires")
+            }) {
+                return property.throw_error(
+                    "'alias', 'deprecated' and 'requires' can not be applied to positional property",
+                );
+            }
+
+            if is_default {
+                return property.throw_error(
+                    "'positional' and 'default' can not be applied to the same property",
+                );
+            }
+
+            bind_positional_slot(&mut positional_slots, index, property)?;
+
+            // Positional value has no name in the attribute, so it is named by its index in the path of the error
+            *prop_paths.last_mut().unwrap() = Some(format!("#{}", index));
+
+            let reading = match parse_fn {
+                Some(parse_fn) => {
+                    let index = proc_macro2::Literal::usize_unsuffixed(index);
+                    super::parse_with::generate_reading(
+                        &reading_ty,
+                        parse_fn,
+                        quote::quote!(value.try_get_positional(#index)),
+                        quote::quote!(value.get_positional(#index)?),
+                    )
+                }
+                None => read_positional_param(
+                    index,
+                    &reading_ty,
+                    property,
+                    ident_is_allowed,
+                    default_value.is_some(),
+                )?,
+            };
+
+            let reading = match &default_value {
+                Some(default_value) => read_with_default_value(reading, default_value),
+                None => reading,
+            };
+
+            let index = proc_macro2::Literal::usize_unsuffixed(index);
+
+            reading_props.push(super::validators::read_with_validation(
+                property,
+                param_attr,
+                reading,
+                quote::quote!(value.try_get_positional(#index).unwrap_or(value)),
+            )?);
+            continue;
+        }
+
+        if is_default {
+            bind_positional_slot(&mut positional_slots, 0, property)?;
+        }
+
+        named_fields.push(prop_name.clone());
+        named_fields.extend(aliases.iter().cloned());
+        param_aliases.push((prop_name.clone(), aliases.clone()));
+
+        if let Some((value, names)) = super::utils::get_requires(param_attr)? {
+            requires.push((prop_name.clone(), value, names));
+        }
+
+        // The single value of the #[default] property may be unnamed
+        let lookup = if is_default {
+            quote::quote!(value.try_get_named_param(#name_expr).or(value.try_get_positional(0)))
+        } else {
+            quote::quote!(value.try_get_named_param(#name_expr))
+        };
+
+        let reading = if let Some(parse_fn) = parse_fn {
+            let reading = super::parse_with::generate_reading(
+                &reading_ty,
+                parse_fn,
+                lookup.clone(),
+                quote::quote!(value.get_named_param(#name_expr)?),
+            );
+
+            match &default_value {
+                Some(default_value) => read_with_default_value(reading, default_value),
+                None => reading,
+            }
+        } else if let Some(default_value) = &default_value {
+            let reading = generate_reading_op(
+                is_default,
+                &name_expr,
+                &property.ty,
+                &property.field.ty,
+                ident_is_allowed,
+            );
+            read_with_default_value(reading, default_value)
+        } else if property.ty.is_vec() && !is_vec_of_u8(&property.ty) {
+            generate_reading_from_vec(&name_expr, get_sub_type(&property.field.ty))
         } else if let PropertyType::OptionOf(sub_ty) = &property.ty {
-            reading_props.push(generate_reading_op(
+            generate_reading_op(
                 is_default,
-                &prop_name,
+                &name_expr,
                 sub_ty,
+                get_sub_type(&property.field.ty),
                 ident_is_allowed,
-            ));
+            )
         } else {
-            reading_props.push(read_param(
-                &prop_name,
-                property,
-                ident_is_allowed,
-                is_default,
+            read_param(&name_expr, property, ident_is_allowed, is_default)
+        };
+
+        // The errors of the validators point to the value
+        let reading = super::validators::read_with_validation(
+            property,
+            param_attr,
+            reading,
+            quote::quote!(#lookup.unwrap_or(value)),
+        )?;
+
+        reading_props.push(read_with_aliases(&prop_name, &aliases, reading));
+    }
+
+    for (index, slot) in positional_slots.iter().enumerate() {
+        if slot.is_none() {
+            return Err(syn::Error::new_spanned(
+                structure_schema.name.get_name_ident(),
+                format!(
+                    "Positional parameter #{} is not bound. Positional parameters must be numbered without gaps",
+                    index
+                ),
             ));
         }
     }
 
-    let name_ident = structure_schema.name.get_name_ident();
+    let positional_amount = positional_slots.len();
+
+    // The rest field takes the unknown parameters, so they are not rejected
+    let check_unknown_params = if has_rest || super::utils::is_allow_unknown(params_attr)? {
+        quote::quote!()
+    } else {
+        quote::quote!(errors.handle(tokens_object.check_for_unknown_params(&Self::get_param_names()));)
+    };
+
+    for (constraint, check_fn) in [
+        ("exclusive", quote::quote!(check_exclusive_params)),
+        ("together", quote::quote!(check_params_together)),
+        (
+            "one_of_required",
+            quote::quote!(check_one_of_required_params),
+        ),
+    ] {
+        for (group, names) in super::utils::get_param_groups(params_attr, constraint)? {
+            let names = resolve_param_names(group, &names, &param_aliases)?;
+            constraints.push(quote::quote!(tokens_object.#check_fn(&[#( #names ),*])));
+        }
+    }
+
+    for (prop_name, value, names) in &requires {
+        let prop_name =
+            resolve_param_names(value, std::slice::from_ref(prop_name), &param_aliases)?;
+        let prop_name = &prop_name[0];
+
+        for name in resolve_param_names(value, names, &param_aliases)? {
+            constraints.push(quote::quote!(tokens_object.check_param_requires(#prop_name, #name)));
+        }
+    }
 
     let from_tokens_object = structure_schema.name.render_try_from_implementation(
         true,
         quote::quote!(types_reader::TokensObject),
-        quote::quote!(syn::Error),
+        quote::quote!(types_reader::TypesReaderError),
         || {
             quote::quote! {
-                #name_ident::check_fields(value)?;
-                    let result = Self{
-                        #( #reading_props )*
-                    };
-                    Ok(result)
+                let mut errors = types_reader::ErrorsAccumulator::new();
+                errors.handle(Self::check_fields(value));
+                errors.handle(Self::check_constraints(value));
+
+                let result = errors.handle(Self::read_fields(value));
+
+                errors.check()?;
+
+                Ok(result.unwrap())
             }
         },
     );
 
+    let mut reading_fields = Vec::new();
+
+    for (((prop_value, prop_type), prop_path), reading) in prop_values
+        .iter()
+        .zip(&prop_types)
+        .zip(&prop_paths)
+        .zip(&reading_props)
+    {
+        let reading = quote::quote! {
+            (|| -> Result<#prop_type, types_reader::TypesReaderError> {
+                let result: #prop_type = #reading;
+                Ok(result)
+            })()
+        };
+
+        let reading = match prop_path {
+            Some(prop_path) => quote::quote!(types_reader::utils::in_field(#prop_path, #reading)),
+            None => reading,
+        };
+
+        reading_fields.push(quote::quote! {
+            let #prop_value: Option<#prop_type> = errors.handle(#reading);
+        });
+    }
+
+    // The struct without a lifetime is read from a TokensObject of any lifetime
+    let (read_fields_generics, read_fields_lifetime) =
+        match structure_schema.name.get_borrow_life_time() {
+            Some(life_time) => (quote::quote!(), life_time.to_token_stream()),
+            None => (quote::quote!(<'s>), quote::quote!('s)),
+        };
+
+    let read_fields_where_clause = structure_schema.name.render_borrow_where_clause();
+
     let check_fields = structure_schema.name.render_implement(|| {
-        let mut add_fields = Vec::new();
+        let mut deprecated = Vec::new();
 
-        for field in structure_schema.get_all() {
-            let name = field.name.as_str();
-            add_fields.push(quote::quote! { #name, });
+        for (names, note) in &deprecated_params {
+            deprecated.push(quote::quote! { (&[#( #names ),*], #note), });
         }
 
+        let param_names = if flattened_types.is_empty() {
+            quote::quote!(vec![#( #named_fields ),*])
+        } else {
+            quote::quote! {
+                let mut result = vec![#( #named_fields ),*];
+                #( result.extend(<#flattened_types>::get_param_names()); )*
+                result
+            }
+        };
+
+        let render_deprecation_warnings = if flattened_types.is_empty() {
+            quote::quote!(types_reader::utils::render_deprecation_warnings(tokens_object, &[#( #deprecated )*]))
+        } else {
+            quote::quote! {
+                let mut result = types_reader::utils::render_deprecation_warnings(tokens_object, &[#( #deprecated )*]);
+                #( result.extend(<#flattened_types>::render_deprecation_warnings(tokens_object)); )*
+                result
+            }
+        };
+
+        let (tokens_object_ident, check_constraints) = if constraints.is_empty() {
+            (quote::quote!(_tokens_object), quote::quote!(Ok(())))
+        } else {
+            let check_constraints = quote::quote! {
+                let mut errors = types_reader::ErrorsAccumulator::new();
+                #( errors.handle(#constraints); )*
+                errors.check()
+            };
+            (quote::quote!(tokens_object), check_constraints)
+        };
+
         quote::quote! {
 
-            pub fn check_fields(tokens_object: &types_reader::TokensObject)->Result<(), syn::Error>{
-                tokens_object.check_for_unknown_params(&[#( #add_fields )*])
+            /// Reads the fields without checking the attribute for the unknown parameters and the constraints.
+            /// Every field is read even if the previous one failed, so all the errors are reported at once
+            pub fn read_fields #read_fields_generics (value: &#read_fields_lifetime types_reader::TokensObject) -> Result<Self, types_reader::TypesReaderError> #read_fields_where_clause {
+                let mut errors = types_reader::ErrorsAccumulator::new();
+
+                #( #reading_fields )*
+
+                errors.check()?;
+
+                let result = Self{
+                    #( #prop_idents: #prop_values.unwrap(), )*
+                };
+                Ok(result)
+            }
+
+            /// Names of the parameters the struct reads, including the ones of the flattened structs
+            pub fn get_param_names() -> Vec<&'static str> {
+                #param_names
+            }
+
+            pub fn check_fields(tokens_object: &types_reader::TokensObject)->Result<(), types_reader::TypesReaderError>{
+                let mut errors = types_reader::ErrorsAccumulator::new();
+                errors.handle(tokens_object.check_for_unknown_positional_params(#positional_amount));
+                // The attribute may be parsed with `DuplicateParamPolicy::CollectAsVec` (E.g.: by `Attributes`)
+                errors.handle(tokens_object.check_for_repeated_params(&[]));
+                #check_unknown_params
+                errors.check()
+            }
+
+            /// Checks the constraints between the parameters: `#[params(exclusive(...))]`, `#[params(together(...))]`,
+            /// `#[params(one_of_required(...))]` and `#[param(requires = "...")]`
+            pub fn check_constraints(#tokens_object_ident: &types_reader::TokensObject)->Result<(), types_reader::TypesReaderError>{
+                #check_constraints
+            }
+
+            /// Makes the compiler warn about the deprecated parameters given in the attribute.
+            /// Put the result into the output of the macro
+            pub fn render_deprecation_warnings(tokens_object: &types_reader::TokensObject) -> types_reader::proc_macro2::TokenStream {
+                #render_deprecation_warnings
             }
 
         }
@@ -110,25 +488,26 @@ pub fn generate_content(
 
 fn generate_reading_op(
     reading_single_param: bool,
-    prop_name: &str,
+    prop_name: &proc_macro2::TokenStream,
     sub_ty: &PropertyType,
+    target_ty: &syn::Type,
     indent_is_allowed: bool,
 ) -> proc_macro2::TokenStream {
     if let PropertyType::RefTo { ty, lifetime: _ } = sub_ty {
         match ty.as_str().as_str() {
             TOKENS_OBJECT_TYPE_NAME => {
                 return quote::quote! {
-                     value.try_get_named_param(#prop_name),
+                     value.try_get_named_param(#prop_name)
                 };
             }
             OPTIONAL_OBJECT_VALUE_TYPE_NAME => {
                 if reading_single_param {
                     return quote::quote! {
-                         value.try_get_value_from_single_or_named(#prop_name)?,
+                         value.try_get_value_from_single_or_named(#prop_name)?
                     };
                 } else {
                     return quote::quote! {
-                         value.try_get_named_param(#prop_name),
+                         value.try_get_named_param(#prop_name)
                     };
                 }
             }
@@ -136,22 +515,24 @@ fn generate_reading_op(
         }
     }
 
-    if sub_ty.is_vec() {
+    if sub_ty.is_vec() && !is_vec_of_u8(sub_ty) {
+        let item_ty = get_sub_type(target_ty);
+
         return quote::quote! {
             if let Some(value) = value.try_get_named_param(#prop_name){
 
                 let items = value.unwrap_as_vec()?;
                 let mut result = Vec::new();
 
-                for item in items {
-                    result.push(item.try_into()?);
+                for (index, item) in items.iter().enumerate() {
+                    result.push(types_reader::utils::in_item(index, TryInto::<#item_ty>::try_into(item))?);
                 }
 
                 Some(result)
 
             }else{
                 None
-            },
+            }
         };
     }
 
@@ -169,7 +550,7 @@ fn generate_reading_op(
                 Some(value.unwrap_as_value()? #any_value_as_string .try_into()?)
             }else{
                 None
-            },
+            }
 
         };
     }
@@ -180,7 +561,7 @@ fn generate_reading_op(
         }
     } else {
         quote::quote! {
-                Some(value.try_into()?)
+                Some(TryInto::<#target_ty>::try_into(value)?)
         }
     };
 
@@ -195,7 +576,7 @@ fn generate_reading_op(
                 }
             }else{
                 None
-            },
+            }
 
         };
     } else {
@@ -212,29 +593,40 @@ fn generate_reading_op(
 
             }else{
                 None
-            },
+            }
 
         };
     }
 }
 
-fn generate_reading_from_vec(prop_name: &str) -> proc_macro2::TokenStream {
+// Vec<u8> is read by the mapper itself, so it accepts a byte string as well as an array
+fn is_vec_of_u8(ty: &PropertyType) -> bool {
+    match ty {
+        PropertyType::VecOf(sub_ty) => sub_ty.is_u8(),
+        _ => false,
+    }
+}
+
+fn generate_reading_from_vec(
+    prop_name: &proc_macro2::TokenStream,
+    item_ty: &syn::Type,
+) -> proc_macro2::TokenStream {
     quote::quote!({
         {
             let mut result = Vec::new();
             let items = value.get_named_param(#prop_name)?.unwrap_as_vec()?;
 
-            for item in items {
-                result.push(item.try_into()?);
+            for (index, item) in items.iter().enumerate() {
+                result.push(types_reader::utils::in_item(index, TryInto::<#item_ty>::try_into(item))?);
             }
 
             result
         }
-    },)
+    })
 }
 
 fn read_param(
-    prop_name: &str,
+    prop_name: &proc_macro2::TokenStream,
     property: &StructProperty,
     ident_is_allowed: bool,
     default: bool,
@@ -243,20 +635,20 @@ fn read_param(
         match ty.as_str().as_str() {
             TOKENS_OBJECT_TYPE_NAME => {
                 return quote::quote! {
-                     value.get_named_param(#prop_name)?,
+                     value.get_named_param(#prop_name)?
                 };
             }
             OBJECT_VALUE_TYPE_NAME => {
                 if default {
                     return quote::quote! {
                          value.get_value_from_single_or_named(#prop_name)?
-                         .try_into()?,
+                         .try_into()?
                     };
                 } else {
                     return quote::quote! {
                          value.get_named_param(#prop_name)?
                          .unwrap_as_value()?
-                         .try_into()?,
+                         .try_into()?
                     };
                 }
             }
@@ -264,12 +656,12 @@ fn read_param(
             OPTIONAL_OBJECT_VALUE_TYPE_NAME => {
                 if default {
                     return quote::quote! {
-                         value.get_value_from_single_or_named(#prop_name)?,
+                         value.get_value_from_single_or_named(#prop_name)?
                     };
                 } else {
                     return quote::quote! {
                          value.get_named_param(#prop_name)?
-                         .unwrap_as_value()?,
+                         .unwrap_as_value()?
                     };
                 }
             }
@@ -289,12 +681,12 @@ fn read_param(
 
         if default {
             return quote::quote! {
-                 value.get_value_from_single_or_named(#prop_name)? #any_value_as_string  .try_into()?,
+                 value.get_value_from_single_or_named(#prop_name)? #any_value_as_string  .try_into()?
             };
         } else {
             return quote::quote! {
                  value.get_named_param(#prop_name)?
-                 .unwrap_as_value()? #any_value_as_string  .try_into()?,
+                 .unwrap_as_value()? #any_value_as_string  .try_into()?
             };
         }
     }
@@ -302,22 +694,288 @@ fn read_param(
     if default {
         if ident_is_allowed {
             return quote::quote! {
-              value.get_value_from_single_or_named(#prop_name)?.unwrap_any_value_as_str()?.try_into()?,
+              value.get_value_from_single_or_named(#prop_name)?.unwrap_any_value_as_str()?.try_into()?
             };
         } else {
             return quote::quote! {
-              value.get_value_from_single_or_named(#prop_name)?.try_into()?,
+              value.get_value_from_single_or_named(#prop_name)?.try_into()?
             };
         }
     } else {
         if ident_is_allowed {
             return quote::quote! {
-              value.get_named_param(#prop_name)?.unwrap_as_value()?.unwrap_any_value_as_str()?.try_into()?,
+              value.get_named_param(#prop_name)?.unwrap_as_value()?.unwrap_any_value_as_str()?.try_into()?
             };
         } else {
+            let ty = &property.field.ty;
             return quote::quote! {
-              value.get_named_param(#prop_name)?.try_into()?,
+              TryInto::<#ty>::try_into(value.get_named_param(#prop_name)?)?
             };
         }
     }
 }
+
+fn bind_positional_slot(
+    positional_slots: &mut Vec<Option<String>>,
+    index: usize,
+    property: &StructProperty,
+) -> Result<(), syn::Error> {
+    if positional_slots.len() <= index {
+        positional_slots.resize(index + 1, None);
+    }
+
+    if let Some(bound_property) = &positional_slots[index] {
+        return property.throw_error(
+            format!(
+                "Positional parameter #{} is already bound to the property '{}'",
+                index, bound_property
+            )
+            .as_str(),
+        );
+    }
+
+    positional_slots[index] = Some(property.name.clone());
+
+    Ok(())
+}
+
+fn read_positional_param(
+    index: usize,
+    ty: &PropertyType,
+    property: &StructProperty,
+    ident_is_allowed: bool,
+    has_default_value: bool,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let index = proc_macro2::Literal::usize_unsuffixed(index);
+
+    if ty.is_vec() && !is_vec_of_u8(ty) {
+        return property.throw_error("'positional' can not be applied to Vec property");
+    }
+
+    if let PropertyType::OptionOf(sub_ty) = ty {
+        if let PropertyType::RefTo { ty, .. } = sub_ty.as_ref() {
+            match ty.as_str().as_str() {
+                TOKENS_OBJECT_TYPE_NAME => {
+                    return Ok(quote::quote! {
+                         value.try_get_positional(#index)
+                    });
+                }
+                OPTIONAL_OBJECT_VALUE_TYPE_NAME => {
+                    return Ok(quote::quote! {
+                        if let Some(value) = value.try_get_positional(#index){
+                            Some(value.unwrap_as_value()?)
+                        }else{
+                            None
+                        }
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        if sub_ty.is_vec() && !is_vec_of_u8(sub_ty) {
+            return property.throw_error("'positional' can not be applied to Vec property");
+        }
+
+        // With the default value the property itself is read as optional
+        let target_ty = if has_default_value {
+            &property.field.ty
+        } else {
+            get_sub_type(&property.field.ty)
+        };
+
+        let reading =
+            read_positional_value(quote::quote!(value), sub_ty, target_ty, ident_is_allowed);
+
+        return Ok(quote::quote! {
+            if let Some(value) = value.try_get_positional(#index){
+                Some(#reading)
+            }else{
+                None
+            }
+        });
+    }
+
+    Ok(read_positional_value(
+        quote::quote!(value.get_positional(#index)?),
+        ty,
+        &property.field.ty,
+        ident_is_allowed,
+    ))
+}
+
+// The rest field takes what the other fields do not read
+fn check_rest_property(
+    property: &StructProperty,
+    param_attr: &TokensObject,
+    has_rest: bool,
+) -> Result<(), syn::Error> {
+    if has_rest {
+        return property.throw_error("Only one property can be marked with 'rest'");
+    }
+
+    if param_attr.unwrap_as_object().len() > 1 {
+        return property
+            .throw_error("'rest' can not be combined with the other parameters of 'param'");
+    }
+
+    if property.attrs.has_attr("has_attribute")
+        || super::utils::is_default(property)
+        || property.attrs.has_attr("positional")
+    {
+        return property.throw_error(
+            "'rest' can not be combined with 'has_attribute', 'default' or 'positional'",
+        );
+    }
+
+    Ok(())
+}
+
+// The flattened struct reads its own parameters, so the property can not be read any other way
+fn check_flattened_property(
+    property: &StructProperty,
+    param_attr: &TokensObject,
+) -> Result<(), syn::Error> {
+    if param_attr.unwrap_as_object().len() > 1 {
+        return property
+            .throw_error("'flatten' can not be combined with the other parameters of 'param'");
+    }
+
+    if property.attrs.has_attr("has_attribute")
+        || super::utils::is_default(property)
+        || property.attrs.has_attr("positional")
+    {
+        return property.throw_error(
+            "'flatten' can not be combined with 'has_attribute', 'default' or 'positional'",
+        );
+    }
+
+    if property.ty.is_option() || property.ty.is_vec() {
+        return property.throw_error("'flatten' can be applied only to MacrosParameters struct");
+    }
+
+    Ok(())
+}
+
+// The names of the parameters in a constraint. A parameter with aliases is checked by the name it is given with
+fn resolve_param_names(
+    value: &TokensObject,
+    names: &[String],
+    param_aliases: &[(String, Vec<String>)],
+) -> Result<Vec<proc_macro2::TokenStream>, syn::Error> {
+    let mut result = Vec::new();
+
+    for name in names {
+        let aliases = match param_aliases
+            .iter()
+            .find(|(param_name, _)| param_name == name)
+        {
+            Some((_, aliases)) => aliases,
+            None => {
+                let candidates: Vec<&str> = param_aliases
+                    .iter()
+                    .map(|(param_name, _)| param_name.as_str())
+                    .collect();
+
+                return Err(value.throw_error_at_value_token(
+                    types_reader_core::utils::format_unknown_name_message(
+                        "Unknown parameter",
+                        name,
+                        &candidates,
+                    )
+                    .as_str(),
+                ));
+            }
+        };
+
+        if aliases.is_empty() {
+            result.push(quote::quote!(#name));
+        } else {
+            result.push(quote::quote! {
+                tokens_object.resolve_param_name(#name, &[#( #aliases ),*]).unwrap_or(#name)
+            });
+        }
+    }
+
+    Ok(result)
+}
+
+fn read_with_aliases(
+    prop_name: &str,
+    aliases: &[String],
+    reading: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if aliases.is_empty() {
+        return reading;
+    }
+
+    quote::quote!({
+        let __param_name = value.resolve_param_name(#prop_name, &[#( #aliases ),*])?;
+        #reading
+    })
+}
+
+// The reading is the Option of the property type. None is replaced with the default value
+fn read_with_default_value(
+    reading: proc_macro2::TokenStream,
+    default_value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    quote::quote!({
+        let result = #reading;
+
+        match result {
+            Some(value) => value,
+            None => #default_value,
+        }
+    })
+}
+
+fn read_positional_value(
+    value: proc_macro2::TokenStream,
+    ty: &PropertyType,
+    target_ty: &syn::Type,
+    ident_is_allowed: bool,
+) -> proc_macro2::TokenStream {
+    if let PropertyType::RefTo { ty, .. } = ty {
+        match ty.as_str().as_str() {
+            TOKENS_OBJECT_TYPE_NAME => return value,
+            OBJECT_VALUE_TYPE_NAME => return quote::quote!(#value.unwrap_as_value()?.try_into()?),
+            OPTIONAL_OBJECT_VALUE_TYPE_NAME => return quote::quote!(#value.unwrap_as_value()?),
+            _ => {}
+        }
+    }
+
+    let ty_str = ty.as_str();
+
+    if ty_str.as_str() == MAYBE_EMPTY_VALUE_TYPE_NAME || ty_str.as_str() == ANY_VALUE_TYPE_NAME {
+        if ident_is_allowed {
+            return quote::quote!(#value.unwrap_as_value()?.unwrap_any_value_as_str()?.try_into()?);
+        } else {
+            return quote::quote!(#value.unwrap_as_value()?.try_into()?);
+        }
+    }
+
+    if ident_is_allowed {
+        quote::quote!(#value.unwrap_as_value()?.unwrap_any_value_as_str()?.try_into()?)
+    } else {
+        quote::quote!(TryInto::<#target_ty>::try_into(#value)?)
+    }
+}
+
+// The type of `Option<T>` or `Vec<T>` the value is read as. `TryInto` is called with the type given,
+// since the where clause of a generic struct makes the compiler infer the type parameter instead
+fn get_sub_type(ty: &syn::Type) -> &syn::Type {
+    if let syn::Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            if let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                for argument in &arguments.args {
+                    if let syn::GenericArgument::Type(sub_ty) = argument {
+                        return sub_ty;
+                    }
+                }
+            }
+        }
+    }
+
+    ty
+}
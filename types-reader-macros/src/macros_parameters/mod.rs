@@ -1,3 +1,6 @@
 mod generate;
 pub use generate::*;
+mod default_value;
+mod parse_with;
 mod utils;
+mod validators;
@@ -0,0 +1,66 @@
+use types_reader_core::{PropertyType, TokensObject};
+
+/// `#[param(parse_with = my_mod::parse_fn)]`
+pub fn get_parse_fn(param_attr: Option<&TokensObject>) -> Result<Option<&syn::Path>, syn::Error> {
+    match param_attr.and_then(|attr| attr.try_get_named_param("parse_with")) {
+        Some(value) => Ok(Some(value.unwrap_as_value()?.unwrap_value()?.get_path()?)),
+        None => Ok(None),
+    }
+}
+
+/// Reads the property with the function instead of `TryInto`. `Option<T>` and `Vec<T>` are read by the
+/// crate: the function gets the value or each item of the array.
+/// `lookup` - the expression of `Option<&TokensObject>` of the parameter.
+/// `get_required` - the expression of `&TokensObject` which is an error if the parameter is absent
+pub fn generate_reading(
+    ty: &PropertyType,
+    parse_fn: &syn::Path,
+    lookup: proc_macro2::TokenStream,
+    get_required: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    match ty {
+        PropertyType::OptionOf(sub_ty) => {
+            let reading = read_value(sub_ty, parse_fn);
+
+            quote::quote! {
+                if let Some(value) = #lookup {
+                    Some(#reading)
+                } else {
+                    None
+                }
+            }
+        }
+        _ => {
+            let reading = read_value(ty, parse_fn);
+
+            quote::quote!({
+                let value = match #lookup {
+                    Some(value) => value,
+                    None => #get_required,
+                };
+
+                #reading
+            })
+        }
+    }
+}
+
+fn read_value(ty: &PropertyType, parse_fn: &syn::Path) -> proc_macro2::TokenStream {
+    if let PropertyType::VecOf(_) = ty {
+        return quote::quote!({
+            let items = value.unwrap_as_vec()?;
+            let mut result = Vec::new();
+
+            for (index, item) in items.iter().enumerate() {
+                result.push(types_reader::utils::in_item(
+                    index,
+                    types_reader::utils::parse_with(item, #parse_fn),
+                )?);
+            }
+
+            result
+        });
+    }
+
+    quote::quote!(types_reader::utils::parse_with(value, #parse_fn)?)
+}
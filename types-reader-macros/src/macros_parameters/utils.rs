@@ -1,4 +1,5 @@
-use types_reader_core::StructProperty;
+use syn::ext::IdentExt;
+use types_reader_core::{StructProperty, StructureSchema, TokensObject};
 
 pub fn is_ident_allowed(case: &StructProperty) -> bool {
     case.attrs.try_get_attr("allow_ident").is_some()
@@ -11,3 +12,255 @@ pub fn is_any_value_as_string(case: &StructProperty) -> bool {
 pub fn is_default(case: &StructProperty) -> bool {
     case.attrs.try_get_attr("default").is_some()
 }
+
+pub fn get_positional_index(case: &StructProperty) -> Result<Option<usize>, syn::Error> {
+    match case.attrs.try_get_attr("positional") {
+        Some(value) => Ok(Some(value.try_into()?)),
+        None => Ok(None),
+    }
+}
+
+/// Parameters of the `#[param(...)]` field attribute
+const PARAM_ATTR_PARAMS: &[&str] = &[
+    "default",
+    "default_fn",
+    "rename",
+    "alias",
+    "deprecated",
+    "range",
+    "non_empty",
+    "len",
+    "one_of",
+    "validate",
+    "requires",
+    "parse_with",
+    "flatten",
+    "rest",
+];
+
+/// Parameters of the `#[params(...)]` struct attribute
+const PARAMS_ATTR_PARAMS: &[&str] = &[
+    "rename_all",
+    "exclusive",
+    "together",
+    "one_of_required",
+    "allow_unknown",
+    "borrow",
+];
+
+pub fn get_param_attr<'s>(
+    case: &'s StructProperty,
+) -> Result<Option<&'s TokensObject>, syn::Error> {
+    match case.attrs.try_get_attr("param") {
+        Some(value) => {
+            value.check_for_unknown_params(PARAM_ATTR_PARAMS)?;
+            value.check_for_repeated_params(&["alias", "requires"])?;
+            Ok(Some(value))
+        }
+        None => Ok(None),
+    }
+}
+
+pub fn get_params_attr<'s>(
+    structure_schema: &'s StructureSchema,
+) -> Result<Option<&'s TokensObject>, syn::Error> {
+    match structure_schema.attrs.try_get_attr("params") {
+        Some(value) => {
+            value.check_for_unknown_params(PARAMS_ATTR_PARAMS)?;
+            value.check_for_repeated_params(&["exclusive", "together", "one_of_required"])?;
+            Ok(Some(value))
+        }
+        None => Ok(None),
+    }
+}
+
+/// `#[params(rename_all = "camelCase")]` of the struct
+pub fn get_rename_all(params_attr: Option<&TokensObject>) -> Result<Option<&str>, syn::Error> {
+    let params_attr = match params_attr {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+
+    let rename_all = match params_attr.try_get_named_param("rename_all") {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+
+    let case: &str = rename_all.try_into()?;
+
+    if types_reader_core::utils::convert_case("", case).is_none() {
+        let mut err = rename_all.throw_error_at_value_token(
+            types_reader_core::utils::format_unknown_name_message(
+                "Unsupported case",
+                case,
+                types_reader_core::utils::RENAME_ALL_CASES,
+            )
+            .as_str(),
+        );
+        err.combine(
+            rename_all.throw_error_at_value_token(
+                types_reader_core::utils::format_supported_names_note(
+                    "cases",
+                    types_reader_core::utils::RENAME_ALL_CASES,
+                )
+                .as_str(),
+            ),
+        );
+        return Err(err);
+    }
+
+    Ok(Some(case))
+}
+
+/// `#[params(allow_unknown)]` - `check_fields` does not reject the parameters the struct does not read
+pub fn is_allow_unknown(params_attr: Option<&TokensObject>) -> Result<bool, syn::Error> {
+    match params_attr.and_then(|attr| attr.try_get_named_param("allow_unknown")) {
+        Some(value) => {
+            if !value.has_no_value() {
+                return Err(
+                    value.throw_error_at_value_token("'allow_unknown' is a flag and has no value")
+                );
+            }
+
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// `#[params(borrow = 'a)]` - the lifetime the struct borrows the attribute with
+pub fn get_borrow_life_time(
+    params_attr: Option<&TokensObject>,
+) -> Result<Option<syn::Lifetime>, syn::Error> {
+    match params_attr.and_then(|attr| attr.try_get_named_param("borrow")) {
+        Some(value) => {
+            let raw_tokens = value.unwrap_as_value()?.unwrap_value()?.as_raw_tokens()?;
+            Ok(Some(raw_tokens.parse_as()?))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Name of the parameter the property is read from: `#[param(rename = "type")]`,
+/// or the field name converted by `rename_all`, or the field name itself. `r#type` is named `type`
+pub fn get_param_name(
+    case: &StructProperty,
+    param_attr: Option<&TokensObject>,
+    rename_all: Option<&str>,
+) -> Result<String, syn::Error> {
+    if let Some(rename) = param_attr.and_then(|attr| attr.try_get_named_param("rename")) {
+        let rename: &str = rename.try_into()?;
+        return Ok(rename.to_string());
+    }
+
+    let field_name = case.get_field_name_ident().unraw().to_string();
+
+    match rename_all {
+        Some(rename_all) => {
+            Ok(types_reader_core::utils::convert_case(&field_name, rename_all).unwrap())
+        }
+        None => Ok(field_name),
+    }
+}
+
+/// `#[param(alias = "desc")]`. Several aliases are given as `alias = "a", alias = "b"` or `alias = ["a", "b"]`
+pub fn get_aliases(param_attr: Option<&TokensObject>) -> Result<Vec<String>, syn::Error> {
+    match param_attr.and_then(|attr| attr.try_get_named_param("alias")) {
+        Some(alias) => read_names(alias),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// `#[param(requires = "cache")]`. Several parameters are given the same way as the aliases
+pub fn get_requires(
+    param_attr: Option<&TokensObject>,
+) -> Result<Option<(&TokensObject, Vec<String>)>, syn::Error> {
+    match param_attr.and_then(|attr| attr.try_get_named_param("requires")) {
+        Some(requires) => Ok(Some((requires, read_names(requires)?))),
+        None => Ok(None),
+    }
+}
+
+/// Groups of parameter names of the `#[params(...)]` constraint: `exclusive(path, regex)`.
+/// The names are given as flags or as strings: `exclusive("path", "regex")`. The constraint may be repeated
+pub fn get_param_groups<'s>(
+    params_attr: Option<&'s TokensObject>,
+    constraint: &str,
+) -> Result<Vec<(&'s TokensObject, Vec<String>)>, syn::Error> {
+    let value = match params_attr.and_then(|attr| attr.try_get_named_param(constraint)) {
+        Some(value) => value,
+        None => return Ok(Vec::new()),
+    };
+
+    let groups = match value.try_get_vec() {
+        Some(items) => items.iter().collect(),
+        None => vec![value],
+    };
+
+    let mut result = Vec::new();
+
+    for group in groups {
+        let mut names = Vec::new();
+
+        for item in group.get_positional_params() {
+            let name: &str = item.try_into()?;
+            names.push(name.to_string());
+        }
+
+        if let Some(items) = group.try_get_object() {
+            for name in items.names() {
+                if !items.get(name).unwrap().has_no_value() {
+                    return Err(items
+                        .get(name)
+                        .unwrap()
+                        .throw_error_at_value_token("Parameter name is expected"));
+                }
+
+                names.push(name.to_string());
+            }
+        }
+
+        if names.len() < 2 {
+            return Err(group.throw_error_at_value_token(
+                format!("'{}' needs at least two parameters", constraint).as_str(),
+            ));
+        }
+
+        result.push((group, names));
+    }
+
+    Ok(result)
+}
+
+// A single name or an array of names
+fn read_names(value: &TokensObject) -> Result<Vec<String>, syn::Error> {
+    let mut result = Vec::new();
+
+    match value.try_get_vec() {
+        Some(items) => {
+            for item in items {
+                let name: &str = item.try_into()?;
+                result.push(name.to_string());
+            }
+        }
+        None => {
+            let name: &str = value.try_into()?;
+            result.push(name.to_string());
+        }
+    }
+
+    Ok(result)
+}
+
+/// `#[param(deprecated = "use `description`")]`
+pub fn get_deprecated_note(
+    param_attr: Option<&TokensObject>,
+) -> Result<Option<String>, syn::Error> {
+    match param_attr.and_then(|attr| attr.try_get_named_param("deprecated")) {
+        Some(note) => {
+            let note: &str = note.try_into()?;
+            Ok(Some(note.to_string()))
+        }
+        None => Ok(None),
+    }
+}
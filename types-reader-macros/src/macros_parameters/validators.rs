@@ -0,0 +1,149 @@
+use types_reader_core::{ObjectValue, StructProperty, TokensObject};
+
+/// Checks the value of the property right after it is read:
+/// `#[param(range = 1..=65535)]`, `#[param(non_empty)]`, `#[param(len = 1..=64)]`,
+/// `#[param(one_of = ["GET", "POST"])]`, `#[param(validate = path::to::fn)]`.
+/// `value_ref` is the expression of the `TokensObject` the errors point to
+pub fn read_with_validation(
+    property: &StructProperty,
+    param_attr: Option<&TokensObject>,
+    reading: proc_macro2::TokenStream,
+    value_ref: proc_macro2::TokenStream,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let param_attr = match param_attr {
+        Some(param_attr) => param_attr,
+        None => return Ok(reading),
+    };
+
+    let checks = generate_checks(param_attr)?;
+
+    if checks.is_empty() {
+        return Ok(reading);
+    }
+
+    // Option is validated only if the value is given
+    let validation = if property.ty.is_option() {
+        quote::quote! {
+            if let Some(__value) = &result {
+                #( #checks )*
+            }
+        }
+    } else {
+        quote::quote! {
+            let __value = &result;
+            #( #checks )*
+        }
+    };
+
+    let ty = &property.field.ty;
+
+    Ok(quote::quote!({
+        let result: #ty = #reading;
+
+        {
+            let __value_ref: &types_reader::TokensObject = #value_ref;
+            #validation
+        }
+
+        result
+    }))
+}
+
+/// `true` if any of the validators is applied to the property
+pub fn has_validators(param_attr: Option<&TokensObject>) -> bool {
+    match param_attr {
+        Some(param_attr) => ["range", "non_empty", "len", "one_of", "validate"]
+            .iter()
+            .any(|name| param_attr.has_param(name)),
+        None => false,
+    }
+}
+
+fn generate_checks(param_attr: &TokensObject) -> Result<Vec<proc_macro2::TokenStream>, syn::Error> {
+    let mut result = Vec::new();
+
+    if let Some(range) = param_attr.try_get_named_param("range") {
+        let range = read_range(range)?;
+        result.push(quote::quote!(types_reader::validators::check_range(__value, #range)));
+    }
+
+    if let Some(non_empty) = param_attr.try_get_named_param("non_empty") {
+        if !non_empty.has_no_value() {
+            return Err(
+                non_empty.throw_error_at_value_token("'non_empty' is a flag and has no value")
+            );
+        }
+
+        result.push(quote::quote!(types_reader::validators::check_non_empty(
+            __value
+        )));
+    }
+
+    if let Some(len) = param_attr.try_get_named_param("len") {
+        let value = len.unwrap_as_value()?.unwrap_value()?;
+
+        // len = 5 is the exact length
+        let range = match value {
+            ObjectValue::Number(_) => {
+                let number = value.to_token_stream();
+                quote::quote!(#number..=#number)
+            }
+            _ => read_range(len)?,
+        };
+
+        result.push(quote::quote!(types_reader::validators::check_len(
+            __value, #range
+        )));
+    }
+
+    if let Some(one_of) = param_attr.try_get_named_param("one_of") {
+        let mut items = Vec::new();
+
+        for item in one_of.unwrap_as_vec()? {
+            let item = item.unwrap_as_value()?.unwrap_value()?;
+
+            // A bare word is compared as a string, the way `#[allow_ident]` reads it
+            match item {
+                ObjectValue::Ident(ident) => {
+                    let ident = ident.as_str();
+                    items.push(quote::quote!(#ident));
+                }
+                _ => items.push(item.to_token_stream()),
+            }
+        }
+
+        result.push(quote::quote!(types_reader::validators::check_one_of(
+            __value,
+            &[#( #items ),*]
+        )));
+    }
+
+    if let Some(validate) = param_attr.try_get_named_param("validate") {
+        let path = validate.unwrap_as_value()?.unwrap_value()?.get_path()?;
+        result.push(quote::quote!(#path(__value)));
+    }
+
+    let result = result
+        .into_iter()
+        .map(|check| {
+            quote::quote! {
+                if let Err(err) = #check {
+                    return Err(types_reader::validators::validation_error(__value_ref, err));
+                }
+            }
+        })
+        .collect();
+
+    Ok(result)
+}
+
+fn read_range(value: &TokensObject) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let value = value.unwrap_as_value()?.unwrap_value()?;
+    let raw_tokens = value.as_raw_tokens()?;
+
+    if !raw_tokens.as_str().contains("..") {
+        return Err(raw_tokens.throw_error("Value should be a range. E.g.: 1..=64"));
+    }
+
+    Ok(value.to_token_stream())
+}